@@ -0,0 +1,30 @@
+//! Compare parsing SDDL SID aliases through the cache against calling Windows
+//! directly.
+
+#[cfg(target_os = "windows")]
+mod bench {
+    use criterion::{black_box, Criterion};
+    use windows_permissions::{wrappers, LocalBox, Sid};
+
+    pub fn sid_alias(c: &mut Criterion) {
+        let mut group = c.benchmark_group("sid_alias");
+
+        group.bench_function("cached", |b| {
+            b.iter(|| black_box("WD").parse::<LocalBox<Sid>>().unwrap())
+        });
+
+        group.bench_function("uncached", |b| {
+            b.iter(|| wrappers::ConvertStringSidToSid(black_box("WD")).unwrap())
+        });
+
+        group.finish();
+    }
+}
+
+#[cfg(target_os = "windows")]
+criterion::criterion_group!(benches, bench::sid_alias);
+#[cfg(target_os = "windows")]
+criterion::criterion_main!(benches);
+
+#[cfg(not(target_os = "windows"))]
+fn main() {}
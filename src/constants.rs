@@ -3,9 +3,12 @@
 #![allow(non_upper_case_globals)]
 #![allow(missing_docs)]
 
-use winapi::um::accctrl::*;
-use winapi::um::minwinbase::*;
-use winapi::um::winnt::*;
+use crate::ffi::shared::sddl::SDDL_REVISION_1;
+use crate::ffi::um::accctrl::*;
+use crate::ffi::um::minwinbase::*;
+use crate::ffi::um::winnt::*;
+use crate::ffi::um::winsvc::*;
+use std::fmt;
 
 /// Create an enum from a list of constants. Generated enums get a method
 /// `from_raw` that allows them to be converted from a value.
@@ -16,7 +19,7 @@ macro_rules! constant_enum {
             $( $item ),*);
     };
     ( $name:ident; $int:ident; doc: $doc:expr; $( $item:ident),* ) => {
-        #[derive(Debug, PartialEq, Copy, Clone)]
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
         #[allow(non_camel_case_types)]
         #[repr(C)]
         #[doc = $doc]
@@ -39,6 +42,38 @@ macro_rules! constant_enum {
     }
 }
 
+/// Implement `Display` for a set of flags, using the Win32 name of each flag.
+/// Set flags are listed in the order given, separated by `" | "`. Any bits
+/// without a name are shown in hex at the end, and an empty set shows as
+/// `"(none)"`.
+macro_rules! flag_display {
+    ( $name:ident; $( $flag:ident => $win32:ident ),* ) => {
+        impl fmt::Display for $name {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                if self.is_empty() {
+                    return fmt.write_str("(none)");
+                }
+
+                let mut separator = "";
+
+                $(
+                    if self.contains($name::$flag) {
+                        write!(fmt, "{}{}", separator, stringify!($win32))?;
+                        separator = " | ";
+                    }
+                )*
+
+                let unnamed = self.bits() & !$name::all().bits();
+                if unnamed != 0 {
+                    write!(fmt, "{}{:#x}", separator, unnamed)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
 constant_enum!(TrusteeForm; u32;
     msdn: "https://docs.microsoft.com/en-us/windows/win32/api/accctrl/ne-accctrl-trustee_form";
     TRUSTEE_IS_SID,
@@ -99,11 +134,91 @@ constant_enum!(AceType; u8;
     SYSTEM_RESOURCE_ATTRIBUTE_ACE_TYPE,
     SYSTEM_SCOPED_POLICY_ID_ACE_TYPE);
 
+impl AceType {
+    /// Check whether ACEs of this type belong in a DACL
+    ///
+    /// These are the access-allowed and access-denied types, which decide
+    /// who can access an object.
+    ///
+    /// ```
+    /// use windows_permissions::constants::AceType;
+    ///
+    /// assert!(AceType::ACCESS_ALLOWED_ACE_TYPE.valid_in_dacl());
+    /// assert!(!AceType::SYSTEM_AUDIT_ACE_TYPE.valid_in_dacl());
+    /// ```
+    pub fn valid_in_dacl(&self) -> bool {
+        matches!(
+            self,
+            AceType::ACCESS_ALLOWED_ACE_TYPE
+                | AceType::ACCESS_ALLOWED_CALLBACK_ACE_TYPE
+                | AceType::ACCESS_ALLOWED_CALLBACK_OBJECT_ACE_TYPE
+                | AceType::ACCESS_ALLOWED_OBJECT_ACE_TYPE
+                | AceType::ACCESS_DENIED_ACE_TYPE
+                | AceType::ACCESS_DENIED_CALLBACK_ACE_TYPE
+                | AceType::ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE
+                | AceType::ACCESS_DENIED_OBJECT_ACE_TYPE
+        )
+    }
+
+    /// Check whether ACEs of this type belong in a SACL
+    ///
+    /// These are the system types: audit ACEs, mandatory labels, resource
+    /// attributes, and scoped policy IDs. Every type is valid in exactly one
+    /// of a DACL or a SACL.
+    ///
+    /// ```
+    /// use windows_permissions::constants::AceType;
+    ///
+    /// assert!(AceType::SYSTEM_AUDIT_ACE_TYPE.valid_in_sacl());
+    /// assert!(!AceType::ACCESS_ALLOWED_ACE_TYPE.valid_in_sacl());
+    /// ```
+    pub fn valid_in_sacl(&self) -> bool {
+        !self.valid_in_dacl()
+    }
+}
+
 constant_enum!(AclRevision; u8;
     msdn: "https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-acl";
     ACL_REVISION,
     ACL_REVISION_DS);
 
+constant_enum!(AccessMode; u32;
+    msdn: "https://docs.microsoft.com/en-us/windows/win32/api/accctrl/ne-accctrl-access_mode";
+    NOT_USED_ACCESS,
+    GRANT_ACCESS,
+    SET_ACCESS,
+    DENY_ACCESS,
+    REVOKE_ACCESS,
+    SET_AUDIT_SUCCESS,
+    SET_AUDIT_FAILURE);
+
+constant_enum!(SddlRevision; u8;
+    msdn: "https://docs.microsoft.com/en-us/windows/win32/api/sddl/nf-sddl-convertsecuritydescriptortostringsecuritydescriptorw#parameters";
+    SDDL_REVISION_1);
+
+// constant_enum! can't mark a variant as #[default]
+#[allow(clippy::derivable_impls)]
+impl Default for SddlRevision {
+    fn default() -> Self {
+        SddlRevision::SDDL_REVISION_1
+    }
+}
+
+constant_enum!(IntegrityLevel; u32;
+    doc: "Integrity levels, as the RID of a mandatory label SID (`S-1-16-x`). See [MSDN](https://docs.microsoft.com/en-us/windows/win32/secauthz/mandatory-integrity-control).";
+    SECURITY_MANDATORY_UNTRUSTED_RID,
+    SECURITY_MANDATORY_LOW_RID,
+    SECURITY_MANDATORY_MEDIUM_RID,
+    SECURITY_MANDATORY_HIGH_RID,
+    SECURITY_MANDATORY_SYSTEM_RID);
+
+constant_enum!(SecurityImpersonationLevel; u32;
+    msdn: "https://docs.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-security_impersonation_level";
+    SecurityAnonymous,
+    SecurityIdentification,
+    SecurityImpersonation,
+    SecurityDelegation);
+
 constant_enum!(SidNameUse; u32;
     msdn: "https://docs.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-sid_name_use";
     SidTypeUser,
@@ -131,6 +246,15 @@ bitflags! {
     }
 }
 
+flag_display!(AceFlags;
+    ContainerInherit => CONTAINER_INHERIT_ACE,
+    ObjectInherit => OBJECT_INHERIT_ACE,
+    NoPropagateInherit => NO_PROPAGATE_INHERIT_ACE,
+    InheritOnly => INHERIT_ONLY_ACE,
+    Inherited => INHERITED_ACE,
+    SuccessfulAccess => SUCCESSFUL_ACCESS_ACE_FLAG,
+    FailedAccess => FAILED_ACCESS_ACE_FLAG);
+
 bitflags! {
     /// See [MSDN](https://docs.microsoft.com/en-us/windows/win32/secauthz/security-information).
     pub struct SecurityInformation: u32 {
@@ -149,6 +273,56 @@ bitflags! {
     }
 }
 
+flag_display!(SecurityInformation;
+    Attribute => ATTRIBUTE_SECURITY_INFORMATION,
+    Backup => BACKUP_SECURITY_INFORMATION,
+    Dacl => DACL_SECURITY_INFORMATION,
+    Group => GROUP_SECURITY_INFORMATION,
+    Label => LABEL_SECURITY_INFORMATION,
+    Owner => OWNER_SECURITY_INFORMATION,
+    ProtectedDacl => PROTECTED_DACL_SECURITY_INFORMATION,
+    ProtectedSacl => PROTECTED_SACL_SECURITY_INFORMATION,
+    Sacl => SACL_SECURITY_INFORMATION,
+    Scope => SCOPE_SECURITY_INFORMATION,
+    UnprotectedDacl => UNPROTECTED_DACL_SECURITY_INFORMATION,
+    UnprotectedSacl => UNPROTECTED_SACL_SECURITY_INFORMATION);
+
+bitflags! {
+    /// See [MSDN](https://docs.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control).
+    pub struct SdControl: u16 {
+        const OwnerDefaulted = SE_OWNER_DEFAULTED;
+        const GroupDefaulted = SE_GROUP_DEFAULTED;
+        const DaclPresent = SE_DACL_PRESENT;
+        const DaclDefaulted = SE_DACL_DEFAULTED;
+        const SaclPresent = SE_SACL_PRESENT;
+        const SaclDefaulted = SE_SACL_DEFAULTED;
+        const DaclAutoInheritReq = SE_DACL_AUTO_INHERIT_REQ;
+        const SaclAutoInheritReq = SE_SACL_AUTO_INHERIT_REQ;
+        const DaclAutoInherited = SE_DACL_AUTO_INHERITED;
+        const SaclAutoInherited = SE_SACL_AUTO_INHERITED;
+        const DaclProtected = SE_DACL_PROTECTED;
+        const SaclProtected = SE_SACL_PROTECTED;
+        const RmControlValid = SE_RM_CONTROL_VALID;
+        const SelfRelative = SE_SELF_RELATIVE;
+    }
+}
+
+flag_display!(SdControl;
+    OwnerDefaulted => SE_OWNER_DEFAULTED,
+    GroupDefaulted => SE_GROUP_DEFAULTED,
+    DaclPresent => SE_DACL_PRESENT,
+    DaclDefaulted => SE_DACL_DEFAULTED,
+    SaclPresent => SE_SACL_PRESENT,
+    SaclDefaulted => SE_SACL_DEFAULTED,
+    DaclAutoInheritReq => SE_DACL_AUTO_INHERIT_REQ,
+    SaclAutoInheritReq => SE_SACL_AUTO_INHERIT_REQ,
+    DaclAutoInherited => SE_DACL_AUTO_INHERITED,
+    SaclAutoInherited => SE_SACL_AUTO_INHERITED,
+    DaclProtected => SE_DACL_PROTECTED,
+    SaclProtected => SE_SACL_PROTECTED,
+    RmControlValid => SE_RM_CONTROL_VALID,
+    SelfRelative => SE_SELF_RELATIVE);
+
 bitflags! {
     /// Flags indicating the right to access a resource.
     ///
@@ -219,6 +393,242 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// The policy of a mandatory label ACE, which says what a lower-integrity
+    /// subject may not do to the object. See
+    /// [MSDN](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-system_mandatory_label_ace).
+    pub struct MandatoryPolicy: u32 {
+        const NoWriteUp = SYSTEM_MANDATORY_LABEL_NO_WRITE_UP;
+        const NoReadUp = SYSTEM_MANDATORY_LABEL_NO_READ_UP;
+        const NoExecuteUp = SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP;
+    }
+}
+
+bitflags! {
+    /// High-level file and directory operations, for use with
+    /// [`AccessRights::for_file_ops`].
+    pub struct FileOps: u32 {
+        /// Read file data, attributes, and extended attributes. For
+        /// directories, this also allows listing the contents.
+        const Read = 1 << 0;
+
+        /// Overwrite file data, attributes, and extended attributes
+        const Write = 1 << 1;
+
+        /// Append to the end of a file. For directories, this allows creating
+        /// subdirectories.
+        const Append = 1 << 2;
+
+        /// Run a file as a program
+        const Execute = 1 << 3;
+
+        /// Delete the file or directory itself
+        const Delete = 1 << 4;
+
+        /// Read the security descriptor, except for the SACL
+        const ReadAcl = 1 << 5;
+
+        /// Change the DACL
+        const WriteAcl = 1 << 6;
+
+        /// Pass through a directory to reach its children
+        const Traverse = 1 << 7;
+    }
+}
+
+impl AccessRights {
+    /// Get the smallest set of file-specific rights that allows `ops`
+    ///
+    /// Unlike the generic rights or [`AccessRights::FileAllAccess`], this
+    /// only includes the bits needed for each operation. `Synchronize` is
+    /// included with any data operation, since synchronous I/O needs it.
+    ///
+    /// ```
+    /// use windows_permissions::constants::{AccessRights, FileOps};
+    ///
+    /// let rights = AccessRights::for_file_ops(FileOps::Read | FileOps::Execute | FileOps::ReadAcl);
+    ///
+    /// assert_eq!(rights, AccessRights::FileGenericRead | AccessRights::FileGenericExecute);
+    /// ```
+    pub fn for_file_ops(ops: FileOps) -> AccessRights {
+        let mapping = [
+            (
+                FileOps::Read,
+                FILE_READ_DATA | FILE_READ_ATTRIBUTES | FILE_READ_EA | SYNCHRONIZE,
+            ),
+            (
+                FileOps::Write,
+                FILE_WRITE_DATA | FILE_WRITE_ATTRIBUTES | FILE_WRITE_EA | SYNCHRONIZE,
+            ),
+            (FileOps::Append, FILE_APPEND_DATA | SYNCHRONIZE),
+            (
+                FileOps::Execute,
+                FILE_EXECUTE | FILE_READ_ATTRIBUTES | SYNCHRONIZE,
+            ),
+            (FileOps::Delete, DELETE),
+            (FileOps::ReadAcl, READ_CONTROL),
+            (FileOps::WriteAcl, WRITE_DAC),
+            (FileOps::Traverse, FILE_TRAVERSE | SYNCHRONIZE),
+        ];
+
+        let bits = mapping
+            .iter()
+            .filter(|(op, _)| ops.contains(*op))
+            .fold(0, |bits, (_, rights)| bits | rights);
+
+        AccessRights::from_bits_truncate(bits)
+    }
+
+    /// Check whether every right in `other` is also in `self`
+    ///
+    /// This is the natural way to check that a granted mask covers a
+    /// required one. Access masks only form a partial order: two masks can
+    /// each contain rights the other lacks, in which case neither is a
+    /// superset of the other. The `Ord` implementation on `AccessRights`
+    /// compares the raw numeric values and says nothing about coverage.
+    ///
+    /// Generic rights are compared as plain bits and are not mapped to
+    /// object-specific rights.
+    ///
+    /// ```
+    /// use windows_permissions::constants::AccessRights;
+    ///
+    /// let granted = AccessRights::ReadControl | AccessRights::WriteDac;
+    ///
+    /// assert!(granted.is_superset_of(AccessRights::ReadControl));
+    /// assert!(!granted.is_superset_of(AccessRights::Delete));
+    /// ```
+    pub fn is_superset_of(self, other: AccessRights) -> bool {
+        self.contains(other)
+    }
+
+    /// Check whether every right in `self` is also in `other`
+    ///
+    /// This is the converse of [`AccessRights::is_superset_of`].
+    pub fn is_subset_of(self, other: AccessRights) -> bool {
+        other.contains(self)
+    }
+
+    /// Get the names of the rights in this mask, as they apply to objects
+    /// of type `object_type`
+    ///
+    /// The low 16 bits of an access mask mean different things for each
+    /// kind of object, so they are named from a table chosen by
+    /// `object_type`. Files, registry keys, and services have tables. For
+    /// other object types, including kernel objects, whose specific rights
+    /// depend on the kind of kernel object, each object-specific bit is
+    /// named by its number. Generic and standard rights are named the same
+    /// way for every type.
+    ///
+    /// Names are listed from the lowest bit to the highest. Composite rights
+    /// such as [`AccessRights::FileAllAccess`] are not recognized; each bit
+    /// is named on its own.
+    ///
+    /// ```
+    /// use windows_permissions::constants::{AccessRights, SeObjectType};
+    ///
+    /// let rights = AccessRights::Bit5 | AccessRights::Delete;
+    ///
+    /// assert_eq!(
+    ///     rights.describe_for(SeObjectType::SE_FILE_OBJECT),
+    ///     vec!["Execute/Traverse", "Delete"]
+    /// );
+    /// assert_eq!(
+    ///     rights.describe_for(SeObjectType::SE_REGISTRY_KEY),
+    ///     vec!["Create Link", "Delete"]
+    /// );
+    /// ```
+    pub fn describe_for(&self, object_type: SeObjectType) -> Vec<&'static str> {
+        let specific = match object_type {
+            SeObjectType::SE_FILE_OBJECT | SeObjectType::SE_LMSHARE => FILE_RIGHT_NAMES,
+            SeObjectType::SE_REGISTRY_KEY
+            | SeObjectType::SE_REGISTRY_WOW64_32KEY
+            | SeObjectType::SE_REGISTRY_WOW64_64KEY => KEY_RIGHT_NAMES,
+            SeObjectType::SE_SERVICE => SERVICE_RIGHT_NAMES,
+            _ => SPECIFIC_RIGHT_NAMES,
+        };
+
+        specific
+            .iter()
+            .chain(STANDARD_RIGHT_NAMES)
+            .filter(|(bit, _)| self.bits() & bit != 0)
+            .map(|&(_, name)| name)
+            .collect()
+    }
+}
+
+/// Names of the rights in bits 16-31 of an access mask
+const STANDARD_RIGHT_NAMES: &[(u32, &str)] = &[
+    (DELETE, "Delete"),
+    (READ_CONTROL, "Read Control"),
+    (WRITE_DAC, "Write DAC"),
+    (WRITE_OWNER, "Write Owner"),
+    (SYNCHRONIZE, "Synchronize"),
+    (ACCESS_SYSTEM_SECURITY, "Access System Security"),
+    (MAXIMUM_ALLOWED, "Maximum Allowed"),
+    (GENERIC_ALL, "Generic All"),
+    (GENERIC_EXECUTE, "Generic Execute"),
+    (GENERIC_WRITE, "Generic Write"),
+    (GENERIC_READ, "Generic Read"),
+];
+
+/// Names of the file and directory rights. Where a bit means something
+/// different for a directory, both meanings are given.
+const FILE_RIGHT_NAMES: &[(u32, &str)] = &[
+    (FILE_READ_DATA, "Read Data/List Directory"),
+    (FILE_WRITE_DATA, "Write Data/Add File"),
+    (FILE_APPEND_DATA, "Append Data/Add Subdirectory"),
+    (FILE_READ_EA, "Read Extended Attributes"),
+    (FILE_WRITE_EA, "Write Extended Attributes"),
+    (FILE_EXECUTE, "Execute/Traverse"),
+    (FILE_DELETE_CHILD, "Delete Child"),
+    (FILE_READ_ATTRIBUTES, "Read Attributes"),
+    (FILE_WRITE_ATTRIBUTES, "Write Attributes"),
+];
+
+/// Names of the registry key rights
+const KEY_RIGHT_NAMES: &[(u32, &str)] = &[
+    (KEY_QUERY_VALUE, "Query Value"),
+    (KEY_SET_VALUE, "Set Value"),
+    (KEY_CREATE_SUB_KEY, "Create Subkey"),
+    (KEY_ENUMERATE_SUB_KEYS, "Enumerate Subkeys"),
+    (KEY_NOTIFY, "Notify"),
+    (KEY_CREATE_LINK, "Create Link"),
+];
+
+/// Names of the service rights
+const SERVICE_RIGHT_NAMES: &[(u32, &str)] = &[
+    (SERVICE_QUERY_CONFIG, "Query Config"),
+    (SERVICE_CHANGE_CONFIG, "Change Config"),
+    (SERVICE_QUERY_STATUS, "Query Status"),
+    (SERVICE_ENUMERATE_DEPENDENTS, "Enumerate Dependents"),
+    (SERVICE_START, "Start"),
+    (SERVICE_STOP, "Stop"),
+    (SERVICE_PAUSE_CONTINUE, "Pause/Continue"),
+    (SERVICE_INTERROGATE, "Interrogate"),
+    (SERVICE_USER_DEFINED_CONTROL, "User-Defined Control"),
+];
+
+/// Names of the object-specific bits, for object types without a table
+const SPECIFIC_RIGHT_NAMES: &[(u32, &str)] = &[
+    (1 << 0, "Specific Right 0"),
+    (1 << 1, "Specific Right 1"),
+    (1 << 2, "Specific Right 2"),
+    (1 << 3, "Specific Right 3"),
+    (1 << 4, "Specific Right 4"),
+    (1 << 5, "Specific Right 5"),
+    (1 << 6, "Specific Right 6"),
+    (1 << 7, "Specific Right 7"),
+    (1 << 8, "Specific Right 8"),
+    (1 << 9, "Specific Right 9"),
+    (1 << 10, "Specific Right 10"),
+    (1 << 11, "Specific Right 11"),
+    (1 << 12, "Specific Right 12"),
+    (1 << 13, "Specific Right 13"),
+    (1 << 14, "Specific Right 14"),
+    (1 << 15, "Specific Right 15"),
+];
+
 bitflags! {
     /// Flags indicating the settings for a local allocation.
     ///
@@ -253,4 +663,205 @@ mod test {
         assert_eq!(enum_b, TestEnum::from_raw(B).unwrap());
         assert_eq!(enum_c, TestEnum::from_raw(C).unwrap());
     }
+
+    #[test]
+    fn access_mode_round_trip() {
+        use super::AccessMode;
+        use crate::ffi::um::accctrl::*;
+
+        let modes = [
+            (NOT_USED_ACCESS, AccessMode::NOT_USED_ACCESS),
+            (GRANT_ACCESS, AccessMode::GRANT_ACCESS),
+            (SET_ACCESS, AccessMode::SET_ACCESS),
+            (DENY_ACCESS, AccessMode::DENY_ACCESS),
+            (REVOKE_ACCESS, AccessMode::REVOKE_ACCESS),
+            (SET_AUDIT_SUCCESS, AccessMode::SET_AUDIT_SUCCESS),
+            (SET_AUDIT_FAILURE, AccessMode::SET_AUDIT_FAILURE),
+        ];
+
+        for (raw, mode) in modes.iter() {
+            assert_eq!(AccessMode::from_raw(*raw), Some(*mode));
+            assert_eq!(*mode as u32, *raw);
+        }
+
+        assert_eq!(AccessMode::from_raw(SET_AUDIT_FAILURE + 1), None);
+    }
+
+    #[test]
+    fn access_rights_subset_relation() {
+        use super::AccessRights;
+
+        let read = AccessRights::ReadControl;
+        let read_write = AccessRights::ReadControl | AccessRights::WriteDac;
+        let delete = AccessRights::Delete;
+
+        // Identical masks are both a subset and a superset of each other
+        assert!(read_write.is_subset_of(read_write));
+        assert!(read_write.is_superset_of(read_write));
+
+        // Strict subset
+        assert!(read.is_subset_of(read_write));
+        assert!(!read.is_superset_of(read_write));
+        assert!(read_write.is_superset_of(read));
+        assert!(!read_write.is_subset_of(read));
+
+        // Disjoint masks are neither
+        assert!(!read.is_subset_of(delete));
+        assert!(!read.is_superset_of(delete));
+        assert!(!delete.is_subset_of(read));
+        assert!(!delete.is_superset_of(read));
+
+        // The empty mask is a subset of everything
+        assert!(AccessRights::empty().is_subset_of(delete));
+        assert!(delete.is_superset_of(AccessRights::empty()));
+    }
+
+    #[test]
+    fn ace_type_acl_validity() {
+        use super::AceType::{self, *};
+
+        let dacl_types = [
+            ACCESS_ALLOWED_ACE_TYPE,
+            ACCESS_ALLOWED_CALLBACK_ACE_TYPE,
+            ACCESS_ALLOWED_CALLBACK_OBJECT_ACE_TYPE,
+            ACCESS_ALLOWED_OBJECT_ACE_TYPE,
+            ACCESS_DENIED_ACE_TYPE,
+            ACCESS_DENIED_CALLBACK_ACE_TYPE,
+            ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE,
+            ACCESS_DENIED_OBJECT_ACE_TYPE,
+        ];
+
+        let sacl_types = [
+            SYSTEM_AUDIT_ACE_TYPE,
+            SYSTEM_AUDIT_CALLBACK_ACE_TYPE,
+            SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE,
+            SYSTEM_AUDIT_OBJECT_ACE_TYPE,
+            SYSTEM_MANDATORY_LABEL_ACE_TYPE,
+            SYSTEM_RESOURCE_ATTRIBUTE_ACE_TYPE,
+            SYSTEM_SCOPED_POLICY_ID_ACE_TYPE,
+        ];
+
+        for ace_type in dacl_types.iter() {
+            assert!(ace_type.valid_in_dacl(), "{:?}", ace_type);
+            assert!(!ace_type.valid_in_sacl(), "{:?}", ace_type);
+        }
+
+        for ace_type in sacl_types.iter() {
+            assert!(!ace_type.valid_in_dacl(), "{:?}", ace_type);
+            assert!(ace_type.valid_in_sacl(), "{:?}", ace_type);
+        }
+
+        // Every type is covered by one of the lists
+        let covered = (0..=u8::MAX)
+            .filter_map(AceType::from_raw)
+            .all(|t| dacl_types.contains(&t) || sacl_types.contains(&t));
+        assert!(covered);
+    }
+
+    #[test]
+    fn for_file_ops() {
+        use super::{AccessRights, FileOps};
+        use crate::ffi::um::winnt::*;
+
+        assert_eq!(
+            AccessRights::for_file_ops(FileOps::Read | FileOps::Traverse).bits(),
+            FILE_READ_DATA | FILE_READ_ATTRIBUTES | FILE_READ_EA | FILE_TRAVERSE | SYNCHRONIZE
+        );
+        assert_eq!(
+            AccessRights::for_file_ops(FileOps::Read | FileOps::Traverse).bits(),
+            0x0010_00A9
+        );
+
+        assert_eq!(
+            AccessRights::for_file_ops(FileOps::Write | FileOps::Append | FileOps::ReadAcl),
+            AccessRights::FileGenericWrite
+        );
+        assert_eq!(
+            AccessRights::for_file_ops(FileOps::Delete),
+            AccessRights::Delete
+        );
+        assert_eq!(
+            AccessRights::for_file_ops(FileOps::empty()),
+            AccessRights::empty()
+        );
+
+        // Everything together still falls short of full control
+        let all = AccessRights::for_file_ops(FileOps::all());
+        assert!(all.is_subset_of(AccessRights::FileAllAccess));
+        assert!(!all.contains(AccessRights::WriteOwner));
+        assert!(!all.intersects(AccessRights::GenericAll | AccessRights::GenericRead));
+    }
+
+    #[test]
+    fn flag_display() {
+        use super::{AceFlags, SdControl, SecurityInformation};
+
+        assert_eq!(
+            (AceFlags::ContainerInherit | AceFlags::ObjectInherit).to_string(),
+            "CONTAINER_INHERIT_ACE | OBJECT_INHERIT_ACE"
+        );
+        assert_eq!(
+            (AceFlags::Inherited | AceFlags::ContainerInherit).to_string(),
+            "CONTAINER_INHERIT_ACE | INHERITED_ACE"
+        );
+        assert_eq!(
+            (SecurityInformation::Owner | SecurityInformation::Dacl).to_string(),
+            "DACL_SECURITY_INFORMATION | OWNER_SECURITY_INFORMATION"
+        );
+        assert_eq!(
+            (SdControl::DaclPresent | SdControl::SelfRelative).to_string(),
+            "SE_DACL_PRESENT | SE_SELF_RELATIVE"
+        );
+        assert_eq!(SdControl::DaclProtected.to_string(), "SE_DACL_PROTECTED");
+
+        assert_eq!(AceFlags::empty().to_string(), "(none)");
+        assert_eq!(SecurityInformation::empty().to_string(), "(none)");
+        assert_eq!(SdControl::empty().to_string(), "(none)");
+
+        // Display is separate from Debug
+        assert_ne!(
+            AceFlags::ContainerInherit.to_string(),
+            format!("{:?}", AceFlags::ContainerInherit)
+        );
+    }
+
+    #[test]
+    fn describe_for_object_types() {
+        use super::{AccessRights, SeObjectType};
+
+        let mask = AccessRights::from_bits_truncate(0x20);
+
+        assert_eq!(
+            mask.describe_for(SeObjectType::SE_FILE_OBJECT),
+            vec!["Execute/Traverse"]
+        );
+        assert_eq!(
+            mask.describe_for(SeObjectType::SE_REGISTRY_KEY),
+            vec!["Create Link"]
+        );
+        assert_eq!(mask.describe_for(SeObjectType::SE_SERVICE), vec!["Stop"]);
+        assert_eq!(
+            mask.describe_for(SeObjectType::SE_KERNEL_OBJECT),
+            vec!["Specific Right 5"]
+        );
+        assert_eq!(
+            mask.describe_for(SeObjectType::SE_PRINTER),
+            vec!["Specific Right 5"]
+        );
+
+        let mask = AccessRights::from_bits_truncate(0x4 | 0x1_0000 | 0x8000_0000);
+
+        assert_eq!(
+            mask.describe_for(SeObjectType::SE_FILE_OBJECT),
+            vec!["Append Data/Add Subdirectory", "Delete", "Generic Read"]
+        );
+        assert_eq!(
+            mask.describe_for(SeObjectType::SE_REGISTRY_KEY),
+            vec!["Create Subkey", "Delete", "Generic Read"]
+        );
+
+        assert!(AccessRights::empty()
+            .describe_for(SeObjectType::SE_FILE_OBJECT)
+            .is_empty());
+    }
 }
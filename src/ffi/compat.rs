@@ -0,0 +1,1157 @@
+//! `winapi`-compatible items backed by `windows-sys`
+//!
+//! Each item has the name, path, and type it has in `winapi`, so that code
+//! written against `winapi` builds unchanged. Constants are converted to
+//! `winapi`'s integer types, and functions are thin shims that convert their
+//! arguments. Structs are `windows-sys`'s own, which have the same layout and
+//! field names. Items only used by tests are only compiled for tests.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    non_camel_case_types,
+    non_snake_case,
+    non_upper_case_globals
+)]
+
+mod all {
+    pub use core::ffi::c_void;
+    use windows_sys::Win32::Foundation;
+    use windows_sys::Win32::Security;
+    use windows_sys::Win32::Security::Authorization;
+    use windows_sys::Win32::Storage::FileSystem;
+    use windows_sys::Win32::System::Memory;
+    use windows_sys::Win32::System::Registry;
+    use windows_sys::Win32::System::Services;
+    use windows_sys::Win32::System::SystemServices;
+    use windows_sys::Win32::System::Threading;
+
+    // Structs, which are the same in both crates
+
+    pub use windows_sys::core::GUID;
+    pub use Authorization::TRUSTEE_W;
+    pub use Foundation::LUID;
+    pub use Security::ACCESS_ALLOWED_ACE;
+    pub use Security::ACCESS_ALLOWED_CALLBACK_ACE;
+    pub use Security::ACCESS_ALLOWED_CALLBACK_OBJECT_ACE;
+    pub use Security::ACCESS_ALLOWED_OBJECT_ACE;
+    pub use Security::ACCESS_DENIED_ACE;
+    pub use Security::ACCESS_DENIED_CALLBACK_OBJECT_ACE;
+    pub use Security::ACCESS_DENIED_OBJECT_ACE;
+    pub use Security::ACE_HEADER;
+    pub use Security::ACL;
+    pub use Security::ACL_SIZE_INFORMATION;
+    pub use Security::GENERIC_MAPPING;
+    pub use Security::LUID_AND_ATTRIBUTES;
+    pub use Security::OBJECT_TYPE_LIST;
+    pub use Security::PRIVILEGE_SET;
+    pub use Security::SECURITY_DESCRIPTOR;
+    pub use Security::SECURITY_DESCRIPTOR_RELATIVE;
+    pub use Security::SID_IDENTIFIER_AUTHORITY;
+    pub use Security::SYSTEM_AUDIT_ACE;
+    pub use Security::SYSTEM_AUDIT_CALLBACK_ACE;
+    pub use Security::SYSTEM_AUDIT_CALLBACK_OBJECT_ACE;
+    pub use Security::SYSTEM_AUDIT_OBJECT_ACE;
+    pub use Security::SYSTEM_MANDATORY_LABEL_ACE;
+    pub use Security::SYSTEM_RESOURCE_ATTRIBUTE_ACE;
+    pub use Security::SYSTEM_SCOPED_POLICY_ID_ACE;
+    pub use Security::TOKEN_PRIVILEGES;
+    pub use Security::TOKEN_USER;
+
+    // Type aliases
+
+    pub type DWORD = u32;
+    pub type PACL = *mut ACL;
+    pub type PSID = *mut c_void;
+    pub type SID_NAME_USE = u32;
+    pub type WCHAR = u16;
+
+    // Constants, converted to the types `winapi` gives them
+
+    pub const ACCESS_ALLOWED_ACE_TYPE: u8 = SystemServices::ACCESS_ALLOWED_ACE_TYPE as u8;
+    pub const ACCESS_ALLOWED_CALLBACK_ACE_TYPE: u8 =
+        SystemServices::ACCESS_ALLOWED_CALLBACK_ACE_TYPE as u8;
+    pub const ACCESS_ALLOWED_CALLBACK_OBJECT_ACE_TYPE: u8 =
+        SystemServices::ACCESS_ALLOWED_CALLBACK_OBJECT_ACE_TYPE as u8;
+    pub const ACCESS_ALLOWED_OBJECT_ACE_TYPE: u8 =
+        SystemServices::ACCESS_ALLOWED_OBJECT_ACE_TYPE as u8;
+    pub const ACCESS_DENIED_ACE_TYPE: u8 = SystemServices::ACCESS_DENIED_ACE_TYPE as u8;
+    pub const ACCESS_DENIED_CALLBACK_ACE_TYPE: u8 =
+        SystemServices::ACCESS_DENIED_CALLBACK_ACE_TYPE as u8;
+    pub const ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE: u8 =
+        SystemServices::ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE as u8;
+    pub const ACCESS_DENIED_OBJECT_ACE_TYPE: u8 =
+        SystemServices::ACCESS_DENIED_OBJECT_ACE_TYPE as u8;
+    #[cfg(test)]
+    pub const ACCESS_OBJECT_GUID: u16 = SystemServices::ACCESS_OBJECT_GUID as u16;
+    pub use Security::ACE_INHERITED_OBJECT_TYPE_PRESENT;
+    pub use Security::ACE_OBJECT_TYPE_PRESENT;
+    pub use SystemServices::ACCESS_SYSTEM_SECURITY;
+    pub const ACL_REVISION: u8 = Security::ACL_REVISION as u8;
+    pub const ACL_REVISION_DS: u8 = Security::ACL_REVISION_DS as u8;
+    pub use Security::ATTRIBUTE_SECURITY_INFORMATION;
+    pub const AclSizeInformation: u32 = Security::AclSizeInformation as u32;
+    pub use Security::BACKUP_SECURITY_INFORMATION;
+    pub const CONTAINER_INHERIT_ACE: u8 = Security::CONTAINER_INHERIT_ACE as u8;
+    pub use FileSystem::DELETE;
+    pub use Security::DACL_SECURITY_INFORMATION;
+    pub const DENY_ACCESS: u32 = Authorization::DENY_ACCESS as u32;
+    #[cfg(test)]
+    pub use Foundation::ERROR_ACCESS_DENIED;
+    pub use Foundation::ERROR_INSUFFICIENT_BUFFER;
+    pub use Foundation::ERROR_INVALID_DATA;
+    pub use Foundation::ERROR_INVALID_PARAMETER;
+    pub use Foundation::ERROR_NONE_MAPPED;
+    #[cfg(test)]
+    pub use Foundation::ERROR_NON_ACCOUNT_SID;
+    pub use Foundation::ERROR_NOT_ALL_ASSIGNED;
+    #[cfg(test)]
+    pub use Foundation::ERROR_NO_SUCH_PRIVILEGE;
+    pub use Foundation::ERROR_NO_TOKEN;
+    pub use Foundation::ERROR_PRIVILEGE_NOT_HELD;
+    pub use Foundation::ERROR_SUCCESS;
+    pub const FAILED_ACCESS_ACE_FLAG: u8 = Security::FAILED_ACCESS_ACE_FLAG as u8;
+    pub use FileSystem::FILE_ALL_ACCESS;
+    pub use FileSystem::FILE_APPEND_DATA;
+    pub use FileSystem::FILE_DELETE_CHILD;
+    pub use FileSystem::FILE_EXECUTE;
+    pub use FileSystem::FILE_GENERIC_EXECUTE;
+    pub use FileSystem::FILE_GENERIC_READ;
+    pub use FileSystem::FILE_GENERIC_WRITE;
+    pub use FileSystem::FILE_READ_ATTRIBUTES;
+    pub use FileSystem::FILE_READ_DATA;
+    pub use FileSystem::FILE_READ_EA;
+    pub use FileSystem::FILE_TRAVERSE;
+    pub use FileSystem::FILE_WRITE_ATTRIBUTES;
+    pub use FileSystem::FILE_WRITE_DATA;
+    pub use FileSystem::FILE_WRITE_EA;
+    pub use Foundation::GENERIC_ALL;
+    pub use Foundation::GENERIC_EXECUTE;
+    pub use Foundation::GENERIC_READ;
+    pub use Foundation::GENERIC_WRITE;
+    pub const GRANT_ACCESS: u32 = Authorization::GRANT_ACCESS as u32;
+    pub use Security::GROUP_SECURITY_INFORMATION;
+    pub const INHERITED_ACE: u8 = Security::INHERITED_ACE as u8;
+    pub const INHERIT_ONLY_ACE: u8 = Security::INHERIT_ONLY_ACE as u8;
+    pub use Memory::LMEM_FIXED;
+    pub use Memory::LMEM_MOVEABLE;
+    pub use Memory::LMEM_ZEROINIT;
+    pub use Registry::KEY_ALL_ACCESS;
+    pub use Registry::KEY_CREATE_LINK;
+    pub use Registry::KEY_CREATE_SUB_KEY;
+    pub use Registry::KEY_ENUMERATE_SUB_KEYS;
+    pub use Registry::KEY_EXECUTE;
+    pub use Registry::KEY_NOTIFY;
+    pub use Registry::KEY_QUERY_VALUE;
+    pub use Registry::KEY_READ;
+    pub use Registry::KEY_SET_VALUE;
+    pub use Registry::KEY_WRITE;
+    pub use Security::LABEL_SECURITY_INFORMATION;
+    pub use SystemServices::LMEM_DISCARDABLE;
+    pub use SystemServices::LMEM_NOCOMPACT;
+    pub use SystemServices::LMEM_NODISCARD;
+    pub use SystemServices::MAXIMUM_ALLOWED;
+    pub const NOT_USED_ACCESS: u32 = Authorization::NOT_USED_ACCESS as u32;
+    pub const NO_MULTIPLE_TRUSTEE: u32 = Authorization::NO_MULTIPLE_TRUSTEE as u32;
+    pub const NO_PROPAGATE_INHERIT_ACE: u8 = Security::NO_PROPAGATE_INHERIT_ACE as u8;
+    pub const OBJECT_INHERIT_ACE: u8 = Security::OBJECT_INHERIT_ACE as u8;
+    pub use FileSystem::READ_CONTROL;
+    pub use Security::OWNER_SECURITY_INFORMATION;
+    pub use Security::PROTECTED_DACL_SECURITY_INFORMATION;
+    pub use Security::PROTECTED_SACL_SECURITY_INFORMATION;
+    pub const REVOKE_ACCESS: u32 = Authorization::REVOKE_ACCESS as u32;
+    pub use Security::SACL_SECURITY_INFORMATION;
+    pub use Security::SCOPE_SECURITY_INFORMATION;
+    pub const SDDL_REVISION_1: u8 = Authorization::SDDL_REVISION_1 as u8;
+    pub const SECURITY_BUILTIN_DOMAIN_RID: u32 = SystemServices::SECURITY_BUILTIN_DOMAIN_RID as u32;
+    pub use SystemServices::SECURITY_DESCRIPTOR_REVISION;
+    pub const SECURITY_MANDATORY_HIGH_RID: u32 = SystemServices::SECURITY_MANDATORY_HIGH_RID as u32;
+    pub const SECURITY_MANDATORY_LABEL_AUTHORITY: [u8; 6] =
+        Security::SECURITY_MANDATORY_LABEL_AUTHORITY.Value;
+    pub const SECURITY_MANDATORY_LOW_RID: u32 = SystemServices::SECURITY_MANDATORY_LOW_RID as u32;
+    pub const SECURITY_MANDATORY_MEDIUM_RID: u32 =
+        SystemServices::SECURITY_MANDATORY_MEDIUM_RID as u32;
+    pub const SECURITY_MANDATORY_SYSTEM_RID: u32 =
+        SystemServices::SECURITY_MANDATORY_SYSTEM_RID as u32;
+    pub const SECURITY_MANDATORY_UNTRUSTED_RID: u32 =
+        SystemServices::SECURITY_MANDATORY_UNTRUSTED_RID as u32;
+    pub const SECURITY_NT_NON_UNIQUE: u32 = SystemServices::SECURITY_NT_NON_UNIQUE as u32;
+    pub use Services::SERVICE_CHANGE_CONFIG;
+    pub use Services::SERVICE_ENUMERATE_DEPENDENTS;
+    pub use Services::SERVICE_INTERROGATE;
+    pub use Services::SERVICE_PAUSE_CONTINUE;
+    pub use Services::SERVICE_QUERY_CONFIG;
+    pub use Services::SERVICE_QUERY_STATUS;
+    pub use Services::SERVICE_START;
+    pub use Services::SERVICE_STOP;
+    pub use Services::SERVICE_USER_DEFINED_CONTROL;
+    pub const SET_ACCESS: u32 = Authorization::SET_ACCESS as u32;
+    pub const SET_AUDIT_FAILURE: u32 = Authorization::SET_AUDIT_FAILURE as u32;
+    pub const SET_AUDIT_SUCCESS: u32 = Authorization::SET_AUDIT_SUCCESS as u32;
+    #[cfg(test)]
+    pub const SE_CHANGE_NOTIFY_NAME: &str = "SeChangeNotifyPrivilege";
+    pub use Security::SE_DACL_AUTO_INHERITED;
+    pub use Security::SE_DACL_AUTO_INHERIT_REQ;
+    pub use Security::SE_DACL_DEFAULTED;
+    pub use Security::SE_DACL_PRESENT;
+    pub use Security::SE_DACL_PROTECTED;
+    pub const SE_DS_OBJECT: u32 = Authorization::SE_DS_OBJECT as u32;
+    pub const SE_DS_OBJECT_ALL: u32 = Authorization::SE_DS_OBJECT_ALL as u32;
+    pub const SE_FILE_OBJECT: u32 = Authorization::SE_FILE_OBJECT as u32;
+    pub use Security::SE_GROUP_DEFAULTED;
+    pub const SE_KERNEL_OBJECT: u32 = Authorization::SE_KERNEL_OBJECT as u32;
+    pub const SE_LMSHARE: u32 = Authorization::SE_LMSHARE as u32;
+    pub use Security::SE_OWNER_DEFAULTED;
+    pub const SE_PRINTER: u32 = Authorization::SE_PRINTER as u32;
+    pub use Security::SE_PRIVILEGE_ENABLED;
+    pub const SE_PROVIDER_DEFINED_OBJECT: u32 = Authorization::SE_PROVIDER_DEFINED_OBJECT as u32;
+    pub const SE_REGISTRY_KEY: u32 = Authorization::SE_REGISTRY_KEY as u32;
+    pub const SE_REGISTRY_WOW64_32KEY: u32 = Authorization::SE_REGISTRY_WOW64_32KEY as u32;
+    pub const SE_REGISTRY_WOW64_64KEY: u32 = Authorization::SE_REGISTRY_WOW64_64KEY as u32;
+    pub use Security::SE_RM_CONTROL_VALID;
+    pub use Security::SE_SACL_AUTO_INHERITED;
+    pub use Security::SE_SACL_AUTO_INHERIT_REQ;
+    pub use Security::SE_SACL_DEFAULTED;
+    pub use Security::SE_SACL_PRESENT;
+    pub use Security::SE_SACL_PROTECTED;
+    pub const SE_SECURITY_NAME: &str = "SeSecurityPrivilege";
+    pub use Security::SE_SELF_RELATIVE;
+    pub const SE_SERVICE: u32 = Authorization::SE_SERVICE as u32;
+    pub const SE_UNKNOWN_OBJECT_TYPE: u32 = Authorization::SE_UNKNOWN_OBJECT_TYPE as u32;
+    pub const SE_WINDOW_OBJECT: u32 = Authorization::SE_WINDOW_OBJECT as u32;
+    pub const SE_WMIGUID_OBJECT: u32 = Authorization::SE_WMIGUID_OBJECT as u32;
+    pub const SID_MAX_SUB_AUTHORITIES: u8 = SystemServices::SID_MAX_SUB_AUTHORITIES as u8;
+    pub use FileSystem::SPECIFIC_RIGHTS_ALL;
+    pub use FileSystem::STANDARD_RIGHTS_ALL;
+    pub use FileSystem::STANDARD_RIGHTS_EXECUTE;
+    pub use FileSystem::STANDARD_RIGHTS_READ;
+    pub use FileSystem::STANDARD_RIGHTS_REQUIRED;
+    pub use FileSystem::STANDARD_RIGHTS_WRITE;
+    pub const SUCCESSFUL_ACCESS_ACE_FLAG: u8 = Security::SUCCESSFUL_ACCESS_ACE_FLAG as u8;
+    pub use FileSystem::SYNCHRONIZE;
+    pub const SYSTEM_AUDIT_ACE_TYPE: u8 = SystemServices::SYSTEM_AUDIT_ACE_TYPE as u8;
+    pub const SYSTEM_AUDIT_CALLBACK_ACE_TYPE: u8 =
+        SystemServices::SYSTEM_AUDIT_CALLBACK_ACE_TYPE as u8;
+    pub const SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE: u8 =
+        SystemServices::SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE as u8;
+    pub const SYSTEM_AUDIT_OBJECT_ACE_TYPE: u8 = SystemServices::SYSTEM_AUDIT_OBJECT_ACE_TYPE as u8;
+    pub const SYSTEM_MANDATORY_LABEL_ACE_TYPE: u8 =
+        SystemServices::SYSTEM_MANDATORY_LABEL_ACE_TYPE as u8;
+    pub use SystemServices::SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP;
+    pub use SystemServices::SYSTEM_MANDATORY_LABEL_NO_READ_UP;
+    pub use SystemServices::SYSTEM_MANDATORY_LABEL_NO_WRITE_UP;
+    pub const SYSTEM_RESOURCE_ATTRIBUTE_ACE_TYPE: u8 =
+        SystemServices::SYSTEM_RESOURCE_ATTRIBUTE_ACE_TYPE as u8;
+    pub const SYSTEM_SCOPED_POLICY_ID_ACE_TYPE: u8 =
+        SystemServices::SYSTEM_SCOPED_POLICY_ID_ACE_TYPE as u8;
+    pub const SecurityAnonymous: u32 = Security::SecurityAnonymous as u32;
+    pub const SecurityDelegation: u32 = Security::SecurityDelegation as u32;
+    pub const SecurityIdentification: u32 = Security::SecurityIdentification as u32;
+    pub const SecurityImpersonation: u32 = Security::SecurityImpersonation as u32;
+    pub const SidTypeAlias: u32 = Security::SidTypeAlias as u32;
+    pub const SidTypeComputer: u32 = Security::SidTypeComputer as u32;
+    pub const SidTypeDeletedAccount: u32 = Security::SidTypeDeletedAccount as u32;
+    pub const SidTypeDomain: u32 = Security::SidTypeDomain as u32;
+    pub const SidTypeGroup: u32 = Security::SidTypeGroup as u32;
+    pub const SidTypeInvalid: u32 = Security::SidTypeInvalid as u32;
+    pub const SidTypeLabel: u32 = Security::SidTypeLabel as u32;
+    pub const SidTypeLogonSession: u32 = Security::SidTypeLogonSession as u32;
+    pub const SidTypeUnknown: u32 = Security::SidTypeUnknown as u32;
+    pub const SidTypeUser: u32 = Security::SidTypeUser as u32;
+    pub const SidTypeWellKnownGroup: u32 = Security::SidTypeWellKnownGroup as u32;
+    pub use Security::TOKEN_ADJUST_PRIVILEGES;
+    #[cfg(test)]
+    pub use Security::TOKEN_DUPLICATE;
+    pub use Security::TOKEN_IMPERSONATE;
+    pub use Security::TOKEN_QUERY;
+    pub const TRUSTEE_BAD_FORM: u32 = Authorization::TRUSTEE_BAD_FORM as u32;
+    pub const TRUSTEE_IS_ALIAS: u32 = Authorization::TRUSTEE_IS_ALIAS as u32;
+    pub const TRUSTEE_IS_COMPUTER: u32 = Authorization::TRUSTEE_IS_COMPUTER as u32;
+    pub const TRUSTEE_IS_DELETED: u32 = Authorization::TRUSTEE_IS_DELETED as u32;
+    pub const TRUSTEE_IS_DOMAIN: u32 = Authorization::TRUSTEE_IS_DOMAIN as u32;
+    pub const TRUSTEE_IS_GROUP: u32 = Authorization::TRUSTEE_IS_GROUP as u32;
+    pub const TRUSTEE_IS_IMPERSONATE: u32 = Authorization::TRUSTEE_IS_IMPERSONATE as u32;
+    pub const TRUSTEE_IS_INVALID: u32 = Authorization::TRUSTEE_IS_INVALID as u32;
+    pub const TRUSTEE_IS_NAME: u32 = Authorization::TRUSTEE_IS_NAME as u32;
+    pub const TRUSTEE_IS_OBJECTS_AND_NAME: u32 = Authorization::TRUSTEE_IS_OBJECTS_AND_NAME as u32;
+    pub const TRUSTEE_IS_OBJECTS_AND_SID: u32 = Authorization::TRUSTEE_IS_OBJECTS_AND_SID as u32;
+    pub const TRUSTEE_IS_SID: u32 = Authorization::TRUSTEE_IS_SID as u32;
+    pub const TRUSTEE_IS_UNKNOWN: u32 = Authorization::TRUSTEE_IS_UNKNOWN as u32;
+    pub const TRUSTEE_IS_USER: u32 = Authorization::TRUSTEE_IS_USER as u32;
+    pub const TRUSTEE_IS_WELL_KNOWN_GROUP: u32 = Authorization::TRUSTEE_IS_WELL_KNOWN_GROUP as u32;
+    pub const TokenUser: u32 = Security::TokenUser as u32;
+    pub use FileSystem::WRITE_DAC;
+    pub use FileSystem::WRITE_OWNER;
+    pub use Security::UNPROTECTED_DACL_SECURITY_INFORMATION;
+    pub use Security::UNPROTECTED_SACL_SECURITY_INFORMATION;
+    #[cfg(test)]
+    pub const WinBuiltinAdministratorsSid: u32 = Security::WinBuiltinAdministratorsSid as u32;
+    #[cfg(test)]
+    pub const WinCapabilityMusicLibrarySid: u32 = Security::WinCapabilityMusicLibrarySid as u32;
+    #[cfg(test)]
+    pub const WinHighLabelSid: u32 = Security::WinHighLabelSid as u32;
+    #[cfg(test)]
+    pub const WinLocalSid: u32 = Security::WinLocalSid as u32;
+    #[cfg(test)]
+    pub const WinLocalSystemSid: u32 = Security::WinLocalSystemSid as u32;
+    #[cfg(test)]
+    pub const WinLowLabelSid: u32 = Security::WinLowLabelSid as u32;
+    #[cfg(test)]
+    pub const WinMediumLabelSid: u32 = Security::WinMediumLabelSid as u32;
+    #[cfg(test)]
+    pub const WinWorldSid: u32 = Security::WinWorldSid as u32;
+
+    // Functions, converting their arguments to the types `windows-sys` expects
+
+    pub unsafe fn AccessCheckByTypeResultList(
+        pSecurityDescriptor: *mut c_void,
+        PrincipalSelfSid: *mut c_void,
+        ClientToken: *mut c_void,
+        DesiredAccess: u32,
+        ObjectTypeList: *mut OBJECT_TYPE_LIST,
+        ObjectTypeListLength: u32,
+        GenericMapping: *mut GENERIC_MAPPING,
+        PrivilegeSet: *mut PRIVILEGE_SET,
+        PrivilegeSetLength: *mut u32,
+        GrantedAccessList: *mut u32,
+        AccessStatusList: *mut u32,
+    ) -> i32 {
+        Security::AccessCheckByTypeResultList(
+            pSecurityDescriptor,
+            PrincipalSelfSid,
+            ClientToken,
+            DesiredAccess,
+            ObjectTypeList,
+            ObjectTypeListLength,
+            GenericMapping as _,
+            PrivilegeSet,
+            PrivilegeSetLength,
+            GrantedAccessList,
+            AccessStatusList,
+        )
+    }
+
+    pub unsafe fn AddAccessAllowedAceEx(
+        pAcl: *mut ACL,
+        dwAceRevision: u32,
+        AceFlags: u32,
+        AccessMask: u32,
+        pSid: *mut c_void,
+    ) -> i32 {
+        Security::AddAccessAllowedAceEx(pAcl, dwAceRevision, AceFlags, AccessMask, pSid)
+    }
+
+    pub unsafe fn AddAccessDeniedAceEx(
+        pAcl: *mut ACL,
+        dwAceRevision: u32,
+        AceFlags: u32,
+        AccessMask: u32,
+        pSid: *mut c_void,
+    ) -> i32 {
+        Security::AddAccessDeniedAceEx(pAcl, dwAceRevision, AceFlags, AccessMask, pSid)
+    }
+
+    pub unsafe fn AddAce(
+        pAcl: *mut ACL,
+        dwAceRevision: u32,
+        dwStartingAceIndex: u32,
+        pAceList: *mut c_void,
+        nAceListLength: u32,
+    ) -> i32 {
+        Security::AddAce(
+            pAcl,
+            dwAceRevision,
+            dwStartingAceIndex,
+            pAceList as _,
+            nAceListLength,
+        )
+    }
+
+    pub unsafe fn AddAuditAccessAceEx(
+        pAcl: *mut ACL,
+        dwAceRevision: u32,
+        AceFlags: u32,
+        dwAccessMask: u32,
+        pSid: *mut c_void,
+        bAuditSuccess: i32,
+        bAuditFailure: i32,
+    ) -> i32 {
+        Security::AddAuditAccessAceEx(
+            pAcl,
+            dwAceRevision,
+            AceFlags,
+            dwAccessMask,
+            pSid,
+            bAuditSuccess,
+            bAuditFailure,
+        )
+    }
+
+    pub unsafe fn AddMandatoryAce(
+        pAcl: *mut ACL,
+        dwAceRevision: u32,
+        AceFlags: u32,
+        MandatoryPolicy: u32,
+        pLabelSid: *mut c_void,
+    ) -> i32 {
+        Security::AddMandatoryAce(pAcl, dwAceRevision, AceFlags, MandatoryPolicy, pLabelSid)
+    }
+
+    pub unsafe fn AdjustTokenPrivileges(
+        TokenHandle: *mut c_void,
+        DisableAllPrivileges: i32,
+        NewState: *mut TOKEN_PRIVILEGES,
+        BufferLength: u32,
+        PreviousState: *mut TOKEN_PRIVILEGES,
+        ReturnLength: *mut u32,
+    ) -> i32 {
+        Security::AdjustTokenPrivileges(
+            TokenHandle,
+            DisableAllPrivileges,
+            NewState as _,
+            BufferLength,
+            PreviousState,
+            ReturnLength,
+        )
+    }
+
+    pub unsafe fn AllocateAndInitializeSid(
+        pIdentifierAuthoirity: *mut SID_IDENTIFIER_AUTHORITY,
+        nSubAuthorityCount: u8,
+        dwSubAuthority0: u32,
+        dwSubAuthority1: u32,
+        dwSubAuthority2: u32,
+        dwSubAuthority3: u32,
+        dwSubAuthority4: u32,
+        dwSubAuthority5: u32,
+        dwSubAuthority6: u32,
+        dwSubAuthority7: u32,
+        pSid: *mut *mut c_void,
+    ) -> i32 {
+        Security::AllocateAndInitializeSid(
+            pIdentifierAuthoirity as _,
+            nSubAuthorityCount,
+            dwSubAuthority0,
+            dwSubAuthority1,
+            dwSubAuthority2,
+            dwSubAuthority3,
+            dwSubAuthority4,
+            dwSubAuthority5,
+            dwSubAuthority6,
+            dwSubAuthority7,
+            pSid,
+        )
+    }
+
+    pub unsafe fn BuildTrusteeWithNameW(pTrustee: *mut TRUSTEE_W, pName: *mut u16) {
+        Authorization::BuildTrusteeWithNameW(pTrustee, pName as _);
+    }
+
+    pub unsafe fn BuildTrusteeWithSidW(pTrustee: *mut TRUSTEE_W, pSid: *mut c_void) {
+        Authorization::BuildTrusteeWithSidW(pTrustee, pSid);
+    }
+
+    pub unsafe fn CheckTokenMembership(
+        TokenHandle: *mut c_void,
+        SidToCheck: *mut c_void,
+        IsMember: *mut i32,
+    ) -> i32 {
+        Security::CheckTokenMembership(TokenHandle, SidToCheck, IsMember)
+    }
+
+    pub unsafe fn CloseHandle(hObject: *mut c_void) -> i32 {
+        Foundation::CloseHandle(hObject)
+    }
+
+    pub unsafe fn ConvertSecurityDescriptorToStringSecurityDescriptorW(
+        SecurityDescriptor: *mut c_void,
+        RequestedStringSDRevision: u32,
+        SecurityInformation: u32,
+        StringSecurityDescriptor: *mut *mut u16,
+        StringSecurityDescriptorLen: *mut u32,
+    ) -> i32 {
+        Authorization::ConvertSecurityDescriptorToStringSecurityDescriptorW(
+            SecurityDescriptor,
+            RequestedStringSDRevision,
+            SecurityInformation,
+            StringSecurityDescriptor,
+            StringSecurityDescriptorLen,
+        )
+    }
+
+    pub unsafe fn ConvertSidToStringSidW(Sid: *mut c_void, StringSid: *mut *mut u16) -> i32 {
+        Authorization::ConvertSidToStringSidW(Sid, StringSid)
+    }
+
+    pub unsafe fn ConvertStringSecurityDescriptorToSecurityDescriptorW(
+        StringSecurityDescriptor: *const u16,
+        StringSDRevision: u32,
+        SecurityDescriptor: *mut *mut c_void,
+        SecurityDescriptorSize: *mut u32,
+    ) -> i32 {
+        Authorization::ConvertStringSecurityDescriptorToSecurityDescriptorW(
+            StringSecurityDescriptor,
+            StringSDRevision,
+            SecurityDescriptor,
+            SecurityDescriptorSize,
+        )
+    }
+
+    pub unsafe fn ConvertStringSidToSidW(StringSid: *const u16, Sid: *mut *mut c_void) -> i32 {
+        Authorization::ConvertStringSidToSidW(StringSid, Sid)
+    }
+
+    pub unsafe fn CopySid(
+        nDestinationSidLength: u32,
+        pDestinationSid: *mut c_void,
+        pSourceSid: *mut c_void,
+    ) -> i32 {
+        Security::CopySid(nDestinationSidLength, pDestinationSid, pSourceSid)
+    }
+
+    pub unsafe fn CreateWellKnownSid(
+        WellKnownSidType: u32,
+        DomainSid: *mut c_void,
+        pSid: *mut c_void,
+        cbSid: *mut u32,
+    ) -> i32 {
+        Security::CreateWellKnownSid(WellKnownSidType as _, DomainSid, pSid, cbSid)
+    }
+
+    pub unsafe fn DeleteAce(pAcl: *mut ACL, dwAceIndex: u32) -> i32 {
+        Security::DeleteAce(pAcl, dwAceIndex)
+    }
+
+    pub unsafe fn DeriveCapabilitySidsFromName(
+        CapName: *const u16,
+        CapabilityGroupSids: *mut *mut *mut c_void,
+        CapabilityGroupSidCount: *mut u32,
+        CapabilitySids: *mut *mut *mut c_void,
+        CapabilitySidCount: *mut u32,
+    ) -> i32 {
+        Security::DeriveCapabilitySidsFromName(
+            CapName,
+            CapabilityGroupSids,
+            CapabilityGroupSidCount,
+            CapabilitySids,
+            CapabilitySidCount,
+        )
+    }
+
+    pub unsafe fn DuplicateToken(
+        ExistingTokenHandle: *mut c_void,
+        ImpersonationLevel: u32,
+        DuplicateTokenHandle: *mut *mut c_void,
+    ) -> i32 {
+        Security::DuplicateToken(
+            ExistingTokenHandle,
+            ImpersonationLevel as _,
+            DuplicateTokenHandle,
+        )
+    }
+
+    pub unsafe fn EqualSid(pSid1: *mut c_void, pSid2: *mut c_void) -> i32 {
+        Security::EqualSid(pSid1, pSid2)
+    }
+
+    pub unsafe fn GetAce(pAcl: *mut ACL, dwAceIndex: u32, pAce: *mut *mut c_void) -> i32 {
+        Security::GetAce(pAcl as _, dwAceIndex, pAce)
+    }
+
+    pub unsafe fn GetAclInformation(
+        pAcl: *mut ACL,
+        pAclInformtion: *mut c_void,
+        nAclInformationLength: u32,
+        dwAclInformationClass: u32,
+    ) -> i32 {
+        Security::GetAclInformation(
+            pAcl as _,
+            pAclInformtion,
+            nAclInformationLength,
+            dwAclInformationClass as _,
+        )
+    }
+
+    pub unsafe fn GetCurrentProcess() -> *mut c_void {
+        Threading::GetCurrentProcess()
+    }
+
+    pub unsafe fn GetCurrentThread() -> *mut c_void {
+        Threading::GetCurrentThread()
+    }
+
+    pub unsafe fn GetEffectiveRightsFromAclW(
+        pacl: *mut ACL,
+        pTrustee: *mut TRUSTEE_W,
+        pAccessRight: *mut u32,
+    ) -> u32 {
+        Authorization::GetEffectiveRightsFromAclW(pacl as _, pTrustee as _, pAccessRight)
+    }
+
+    #[cfg(test)]
+    pub unsafe fn GetHandleInformation(hObject: *mut c_void, lpdwFlags: *mut u32) -> i32 {
+        Foundation::GetHandleInformation(hObject, lpdwFlags)
+    }
+
+    pub unsafe fn GetNamedSecurityInfoW(
+        pObjectName: *const u16,
+        ObjectType: u32,
+        SecurityInfo: u32,
+        ppsidOwner: *mut *mut c_void,
+        ppsidGroup: *mut *mut c_void,
+        ppDacl: *mut *mut ACL,
+        ppSacl: *mut *mut ACL,
+        ppSecurityDescriptor: *mut *mut c_void,
+    ) -> u32 {
+        Authorization::GetNamedSecurityInfoW(
+            pObjectName,
+            ObjectType as _,
+            SecurityInfo,
+            ppsidOwner,
+            ppsidGroup,
+            ppDacl,
+            ppSacl,
+            ppSecurityDescriptor,
+        )
+    }
+
+    pub unsafe fn GetSecurityDescriptorControl(
+        pSecurityDescriptor: *mut c_void,
+        pControl: *mut u16,
+        lpdwRevision: *mut u32,
+    ) -> i32 {
+        Security::GetSecurityDescriptorControl(pSecurityDescriptor, pControl, lpdwRevision)
+    }
+
+    pub unsafe fn GetSecurityDescriptorDacl(
+        pSecurityDescriptor: *mut c_void,
+        lpbDaclPresent: *mut i32,
+        pDacl: *mut *mut ACL,
+        lpbDaclDefaulted: *mut i32,
+    ) -> i32 {
+        Security::GetSecurityDescriptorDacl(
+            pSecurityDescriptor,
+            lpbDaclPresent,
+            pDacl,
+            lpbDaclDefaulted,
+        )
+    }
+
+    pub unsafe fn GetSecurityDescriptorGroup(
+        pSecurityDescriptor: *mut c_void,
+        pGroup: *mut *mut c_void,
+        lpbGroupDefaulted: *mut i32,
+    ) -> i32 {
+        Security::GetSecurityDescriptorGroup(pSecurityDescriptor, pGroup, lpbGroupDefaulted)
+    }
+
+    pub unsafe fn GetSecurityDescriptorLength(pSecurityDescriptor: *mut c_void) -> u32 {
+        Security::GetSecurityDescriptorLength(pSecurityDescriptor)
+    }
+
+    pub unsafe fn GetSecurityDescriptorOwner(
+        pSecurityDescriptor: *mut c_void,
+        pOwner: *mut *mut c_void,
+        lpbOwnerDefaulted: *mut i32,
+    ) -> i32 {
+        Security::GetSecurityDescriptorOwner(pSecurityDescriptor, pOwner, lpbOwnerDefaulted)
+    }
+
+    pub unsafe fn GetSecurityDescriptorRMControl(
+        SecurityDescriptor: *mut c_void,
+        RMControl: *mut u8,
+    ) -> u32 {
+        Security::GetSecurityDescriptorRMControl(SecurityDescriptor, RMControl)
+    }
+
+    pub unsafe fn GetSecurityDescriptorSacl(
+        pSecurityDescriptor: *mut c_void,
+        lpbSaclPresent: *mut i32,
+        pSacl: *mut *mut ACL,
+        lpbSaclDefaulted: *mut i32,
+    ) -> i32 {
+        Security::GetSecurityDescriptorSacl(
+            pSecurityDescriptor,
+            lpbSaclPresent,
+            pSacl,
+            lpbSaclDefaulted,
+        )
+    }
+
+    pub unsafe fn GetSecurityInfo(
+        handle: *mut c_void,
+        ObjectType: u32,
+        SecurityInfo: u32,
+        ppsidOwner: *mut *mut c_void,
+        ppsidGroup: *mut *mut c_void,
+        ppDacl: *mut *mut ACL,
+        ppSacl: *mut *mut ACL,
+        ppSecurityDescriptor: *mut *mut c_void,
+    ) -> u32 {
+        Authorization::GetSecurityInfo(
+            handle,
+            ObjectType as _,
+            SecurityInfo,
+            ppsidOwner,
+            ppsidGroup,
+            ppDacl,
+            ppSacl,
+            ppSecurityDescriptor,
+        )
+    }
+
+    pub unsafe fn GetSidIdentifierAuthority(pSid: *mut c_void) -> *mut SID_IDENTIFIER_AUTHORITY {
+        Security::GetSidIdentifierAuthority(pSid)
+    }
+
+    pub unsafe fn GetSidLengthRequired(nSubAuthorityCount: u8) -> u32 {
+        Security::GetSidLengthRequired(nSubAuthorityCount)
+    }
+
+    pub unsafe fn GetSidSubAuthority(pSid: *mut c_void, nSubAuthority: u32) -> *mut u32 {
+        Security::GetSidSubAuthority(pSid, nSubAuthority)
+    }
+
+    pub unsafe fn GetSidSubAuthorityCount(pSid: *mut c_void) -> *mut u8 {
+        Security::GetSidSubAuthorityCount(pSid)
+    }
+
+    pub unsafe fn GetTokenInformation(
+        TokenHandle: *mut c_void,
+        TokenInformationClass: u32,
+        TokenInformation: *mut c_void,
+        TokenInformationLength: u32,
+        ReturnLength: *mut u32,
+    ) -> i32 {
+        Security::GetTokenInformation(
+            TokenHandle,
+            TokenInformationClass as _,
+            TokenInformation,
+            TokenInformationLength,
+            ReturnLength,
+        )
+    }
+
+    pub unsafe fn GetTrusteeFormW(pTrustee: *mut TRUSTEE_W) -> u32 {
+        Authorization::GetTrusteeFormW(pTrustee as _) as _
+    }
+
+    pub unsafe fn GetTrusteeNameW(pTrustee: *mut TRUSTEE_W) -> *mut u16 {
+        Authorization::GetTrusteeNameW(pTrustee as _)
+    }
+
+    pub unsafe fn GetWindowsAccountDomainSid(
+        pSid: *mut c_void,
+        pDomainSid: *mut c_void,
+        cbDomainSid: *mut u32,
+    ) -> i32 {
+        Security::GetWindowsAccountDomainSid(pSid, pDomainSid, cbDomainSid)
+    }
+
+    pub unsafe fn ImpersonateSelf(ImpersonationLevel: u32) -> i32 {
+        Security::ImpersonateSelf(ImpersonationLevel as _)
+    }
+
+    pub unsafe fn InitializeAcl(pAcl: *mut ACL, nAclLength: u32, dwAclRevision: u32) -> i32 {
+        Security::InitializeAcl(pAcl, nAclLength, dwAclRevision)
+    }
+
+    pub unsafe fn InitializeSecurityDescriptor(
+        pSecurityDescriptor: *mut c_void,
+        dwRevision: u32,
+    ) -> i32 {
+        Security::InitializeSecurityDescriptor(pSecurityDescriptor, dwRevision)
+    }
+
+    pub unsafe fn InitializeSid(
+        Sid: *mut c_void,
+        pIdentifierAuthority: *mut SID_IDENTIFIER_AUTHORITY,
+        nSubAuthorityCount: u8,
+    ) -> i32 {
+        Security::InitializeSid(Sid, pIdentifierAuthority as _, nSubAuthorityCount)
+    }
+
+    pub unsafe fn IsValidAcl(pAcl: *mut ACL) -> i32 {
+        Security::IsValidAcl(pAcl as _)
+    }
+
+    pub unsafe fn IsValidSecurityDescriptor(pSecurityDescriptor: *mut c_void) -> i32 {
+        Security::IsValidSecurityDescriptor(pSecurityDescriptor)
+    }
+
+    pub unsafe fn IsValidSid(pSid: *mut c_void) -> i32 {
+        Security::IsValidSid(pSid)
+    }
+
+    pub unsafe fn LocalAlloc(uFlags: u32, uBytes: usize) -> *mut c_void {
+        Memory::LocalAlloc(uFlags, uBytes)
+    }
+
+    pub unsafe fn LocalFree(hMem: *mut c_void) -> *mut c_void {
+        Foundation::LocalFree(hMem)
+    }
+
+    pub unsafe fn LookupAccountNameW(
+        lpSystemName: *const u16,
+        lpAccountName: *const u16,
+        Sid: *mut c_void,
+        cbSid: *mut u32,
+        ReferencedDomainName: *const u16,
+        cchReferencedDomainName: *mut u32,
+        peUse: *mut u32,
+    ) -> i32 {
+        Security::LookupAccountNameW(
+            lpSystemName,
+            lpAccountName,
+            Sid,
+            cbSid,
+            ReferencedDomainName as _,
+            cchReferencedDomainName,
+            peUse as _,
+        )
+    }
+
+    pub unsafe fn LookupAccountSidW(
+        lpSystemName: *const u16,
+        Sid: *mut c_void,
+        Name: *mut u16,
+        cchName: *mut u32,
+        ReferencedDomainName: *mut u16,
+        cchReferencedDomainName: *mut u32,
+        peUse: *mut u32,
+    ) -> i32 {
+        Security::LookupAccountSidW(
+            lpSystemName,
+            Sid,
+            Name,
+            cchName,
+            ReferencedDomainName,
+            cchReferencedDomainName,
+            peUse as _,
+        )
+    }
+
+    pub unsafe fn LookupPrivilegeValueW(
+        lpSystemName: *const u16,
+        lpName: *const u16,
+        lpLuid: *mut LUID,
+    ) -> i32 {
+        Security::LookupPrivilegeValueW(lpSystemName, lpName, lpLuid)
+    }
+
+    pub unsafe fn MakeAbsoluteSD(
+        pSelfRelativeSD: *mut c_void,
+        pAbsoluteSD: *mut c_void,
+        lpdwAbsoluteSDSize: *mut u32,
+        pDacl: *mut ACL,
+        lpdwDaclSize: *mut u32,
+        pSacl: *mut ACL,
+        lpdwSaclSize: *mut u32,
+        pOwner: *mut c_void,
+        lpdwOwnerSize: *mut u32,
+        pPrimaryGroup: *mut c_void,
+        lpdwPrimaryGroupSize: *mut u32,
+    ) -> i32 {
+        Security::MakeAbsoluteSD(
+            pSelfRelativeSD,
+            pAbsoluteSD,
+            lpdwAbsoluteSDSize,
+            pDacl,
+            lpdwDaclSize,
+            pSacl,
+            lpdwSaclSize,
+            pOwner,
+            lpdwOwnerSize,
+            pPrimaryGroup,
+            lpdwPrimaryGroupSize,
+        )
+    }
+
+    pub unsafe fn MakeSelfRelativeSD(
+        pAbsoluteSD: *mut c_void,
+        pSelfRelativeSD: *mut c_void,
+        lpdwBufferLength: *mut u32,
+    ) -> i32 {
+        Security::MakeSelfRelativeSD(pAbsoluteSD, pSelfRelativeSD, lpdwBufferLength)
+    }
+
+    pub unsafe fn OpenProcessToken(
+        ProcessHandle: *mut c_void,
+        DesiredAccess: u32,
+        TokenHandle: *mut *mut c_void,
+    ) -> i32 {
+        Threading::OpenProcessToken(ProcessHandle, DesiredAccess, TokenHandle)
+    }
+
+    pub unsafe fn OpenThreadToken(
+        ThreadHandle: *mut c_void,
+        DesiredAccess: u32,
+        OpenAsSelf: i32,
+        TokenHandle: *mut *mut c_void,
+    ) -> i32 {
+        Threading::OpenThreadToken(ThreadHandle, DesiredAccess, OpenAsSelf, TokenHandle)
+    }
+
+    pub unsafe fn SetNamedSecurityInfoW(
+        pObjectame: *mut u16,
+        ObjectType: u32,
+        SecurityInfo: u32,
+        psidOwner: *mut c_void,
+        psidGroup: *mut c_void,
+        pDacl: *mut ACL,
+        pSacl: *mut ACL,
+    ) -> u32 {
+        Authorization::SetNamedSecurityInfoW(
+            pObjectame as _,
+            ObjectType as _,
+            SecurityInfo,
+            psidOwner,
+            psidGroup,
+            pDacl as _,
+            pSacl as _,
+        )
+    }
+
+    pub unsafe fn SetSecurityDescriptorDacl(
+        pSecurityDescriptor: *mut c_void,
+        bDaclPresent: i32,
+        pDacl: *mut ACL,
+        bDaclDefaulted: i32,
+    ) -> i32 {
+        Security::SetSecurityDescriptorDacl(
+            pSecurityDescriptor,
+            bDaclPresent,
+            pDacl as _,
+            bDaclDefaulted,
+        )
+    }
+
+    pub unsafe fn SetSecurityDescriptorGroup(
+        pSecurityDescriptor: *mut c_void,
+        pGroup: *mut c_void,
+        bGroupDefaulted: i32,
+    ) -> i32 {
+        Security::SetSecurityDescriptorGroup(pSecurityDescriptor, pGroup, bGroupDefaulted)
+    }
+
+    pub unsafe fn SetSecurityDescriptorOwner(
+        pSecurityDescriptor: *mut c_void,
+        pOwner: *mut c_void,
+        bOwnerDefaulted: i32,
+    ) -> i32 {
+        Security::SetSecurityDescriptorOwner(pSecurityDescriptor, pOwner, bOwnerDefaulted)
+    }
+
+    pub unsafe fn SetSecurityDescriptorRMControl(
+        pSecurityDescriptor: *mut c_void,
+        RMControl: *mut u8,
+    ) -> u32 {
+        Security::SetSecurityDescriptorRMControl(pSecurityDescriptor, RMControl as _)
+    }
+
+    pub unsafe fn SetSecurityDescriptorSacl(
+        pSecurityDescriptor: *mut c_void,
+        bSaclPresent: i32,
+        pSacl: *mut ACL,
+        bSaclDefaulted: i32,
+    ) -> i32 {
+        Security::SetSecurityDescriptorSacl(
+            pSecurityDescriptor,
+            bSaclPresent,
+            pSacl as _,
+            bSaclDefaulted,
+        )
+    }
+
+    pub unsafe fn SetSecurityInfo(
+        handle: *mut c_void,
+        ObjectType: u32,
+        SecurityInfo: u32,
+        psidOwner: *mut c_void,
+        psidGroup: *mut c_void,
+        pDacl: *mut ACL,
+        pSacl: *mut ACL,
+    ) -> u32 {
+        Authorization::SetSecurityInfo(
+            handle,
+            ObjectType as _,
+            SecurityInfo,
+            psidOwner,
+            psidGroup,
+            pDacl as _,
+            pSacl as _,
+        )
+    }
+
+    pub unsafe fn SetThreadToken(Thread: *mut *mut c_void, Token: *mut c_void) -> i32 {
+        Threading::SetThreadToken(Thread as _, Token)
+    }
+}
+
+pub mod ctypes {
+    pub use super::all::c_void;
+}
+
+pub mod shared {
+    pub mod guiddef {
+        pub use super::super::all::GUID;
+    }
+
+    pub mod minwindef {
+        pub use super::super::all::DWORD;
+    }
+
+    pub mod sddl {
+        pub use super::super::all::{
+            ConvertSecurityDescriptorToStringSecurityDescriptorW, ConvertSidToStringSidW,
+            ConvertStringSecurityDescriptorToSecurityDescriptorW, ConvertStringSidToSidW,
+            SDDL_REVISION_1,
+        };
+    }
+
+    pub mod winerror {
+        #[cfg(test)]
+        pub use super::super::all::{
+            ERROR_ACCESS_DENIED, ERROR_NON_ACCOUNT_SID, ERROR_NO_SUCH_PRIVILEGE,
+        };
+        pub use super::super::all::{
+            ERROR_INSUFFICIENT_BUFFER, ERROR_INVALID_DATA, ERROR_INVALID_PARAMETER,
+            ERROR_NONE_MAPPED, ERROR_NOT_ALL_ASSIGNED, ERROR_NO_TOKEN, ERROR_PRIVILEGE_NOT_HELD,
+            ERROR_SUCCESS,
+        };
+    }
+}
+
+pub mod um {
+    pub mod accctrl {
+        pub use super::super::all::{
+            DENY_ACCESS, GRANT_ACCESS, NOT_USED_ACCESS, NO_MULTIPLE_TRUSTEE, REVOKE_ACCESS,
+            SET_ACCESS, SET_AUDIT_FAILURE, SET_AUDIT_SUCCESS, SE_DS_OBJECT, SE_DS_OBJECT_ALL,
+            SE_FILE_OBJECT, SE_KERNEL_OBJECT, SE_LMSHARE, SE_PRINTER, SE_PROVIDER_DEFINED_OBJECT,
+            SE_REGISTRY_KEY, SE_REGISTRY_WOW64_32KEY, SE_REGISTRY_WOW64_64KEY, SE_SERVICE,
+            SE_UNKNOWN_OBJECT_TYPE, SE_WINDOW_OBJECT, SE_WMIGUID_OBJECT, TRUSTEE_BAD_FORM,
+            TRUSTEE_IS_ALIAS, TRUSTEE_IS_COMPUTER, TRUSTEE_IS_DELETED, TRUSTEE_IS_DOMAIN,
+            TRUSTEE_IS_GROUP, TRUSTEE_IS_IMPERSONATE, TRUSTEE_IS_INVALID, TRUSTEE_IS_NAME,
+            TRUSTEE_IS_OBJECTS_AND_NAME, TRUSTEE_IS_OBJECTS_AND_SID, TRUSTEE_IS_SID,
+            TRUSTEE_IS_UNKNOWN, TRUSTEE_IS_USER, TRUSTEE_IS_WELL_KNOWN_GROUP, TRUSTEE_W,
+        };
+    }
+
+    pub mod aclapi {
+        pub use super::super::all::{
+            BuildTrusteeWithNameW, BuildTrusteeWithSidW, GetEffectiveRightsFromAclW,
+            GetNamedSecurityInfoW, GetSecurityInfo, GetTrusteeFormW, GetTrusteeNameW,
+            SetNamedSecurityInfoW, SetSecurityInfo,
+        };
+    }
+
+    pub mod handleapi {
+        pub use super::super::all::CloseHandle;
+        #[cfg(test)]
+        pub use super::super::all::GetHandleInformation;
+    }
+
+    pub mod minwinbase {
+        pub use super::super::all::{
+            LMEM_DISCARDABLE, LMEM_FIXED, LMEM_MOVEABLE, LMEM_NOCOMPACT, LMEM_NODISCARD,
+            LMEM_ZEROINIT,
+        };
+    }
+
+    pub mod processthreadsapi {
+        pub use super::super::all::{
+            GetCurrentProcess, GetCurrentThread, OpenProcessToken, OpenThreadToken, SetThreadToken,
+        };
+    }
+
+    pub mod securitybaseapi {
+        pub use super::super::all::{
+            AccessCheckByTypeResultList, AddAccessAllowedAceEx, AddAccessDeniedAceEx, AddAce,
+            AddAuditAccessAceEx, AddMandatoryAce, AdjustTokenPrivileges, AllocateAndInitializeSid,
+            CheckTokenMembership, CopySid, CreateWellKnownSid, DeleteAce,
+            DeriveCapabilitySidsFromName, DuplicateToken, EqualSid, GetAce, GetAclInformation,
+            GetSecurityDescriptorControl, GetSecurityDescriptorDacl, GetSecurityDescriptorGroup,
+            GetSecurityDescriptorLength, GetSecurityDescriptorOwner,
+            GetSecurityDescriptorRMControl, GetSecurityDescriptorSacl, GetSidIdentifierAuthority,
+            GetSidLengthRequired, GetSidSubAuthority, GetSidSubAuthorityCount, GetTokenInformation,
+            GetWindowsAccountDomainSid, ImpersonateSelf, InitializeAcl,
+            InitializeSecurityDescriptor, InitializeSid, IsValidAcl, IsValidSecurityDescriptor,
+            IsValidSid, MakeAbsoluteSD, MakeSelfRelativeSD, SetSecurityDescriptorDacl,
+            SetSecurityDescriptorGroup, SetSecurityDescriptorOwner, SetSecurityDescriptorRMControl,
+            SetSecurityDescriptorSacl,
+        };
+    }
+
+    pub mod winbase {
+        pub use super::super::all::{
+            LocalAlloc, LocalFree, LookupAccountNameW, LookupAccountSidW, LookupPrivilegeValueW,
+        };
+    }
+
+    pub mod winnt {
+        pub use super::super::all::{
+            AclSizeInformation, SecurityAnonymous, SecurityDelegation, SecurityIdentification,
+            SecurityImpersonation, SidTypeAlias, SidTypeComputer, SidTypeDeletedAccount,
+            SidTypeDomain, SidTypeGroup, SidTypeInvalid, SidTypeLabel, SidTypeLogonSession,
+            SidTypeUnknown, SidTypeUser, SidTypeWellKnownGroup, TokenUser, ACCESS_ALLOWED_ACE,
+            ACCESS_ALLOWED_ACE_TYPE, ACCESS_ALLOWED_CALLBACK_ACE, ACCESS_ALLOWED_CALLBACK_ACE_TYPE,
+            ACCESS_ALLOWED_CALLBACK_OBJECT_ACE, ACCESS_ALLOWED_CALLBACK_OBJECT_ACE_TYPE,
+            ACCESS_ALLOWED_OBJECT_ACE, ACCESS_ALLOWED_OBJECT_ACE_TYPE, ACCESS_DENIED_ACE,
+            ACCESS_DENIED_ACE_TYPE, ACCESS_DENIED_CALLBACK_ACE_TYPE,
+            ACCESS_DENIED_CALLBACK_OBJECT_ACE, ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE,
+            ACCESS_DENIED_OBJECT_ACE, ACCESS_DENIED_OBJECT_ACE_TYPE, ACCESS_SYSTEM_SECURITY,
+            ACE_HEADER, ACE_INHERITED_OBJECT_TYPE_PRESENT, ACE_OBJECT_TYPE_PRESENT, ACL,
+            ACL_REVISION, ACL_REVISION_DS, ACL_SIZE_INFORMATION, ATTRIBUTE_SECURITY_INFORMATION,
+            BACKUP_SECURITY_INFORMATION, CONTAINER_INHERIT_ACE, DACL_SECURITY_INFORMATION, DELETE,
+            FAILED_ACCESS_ACE_FLAG, FILE_ALL_ACCESS, FILE_APPEND_DATA, FILE_DELETE_CHILD,
+            FILE_EXECUTE, FILE_GENERIC_EXECUTE, FILE_GENERIC_READ, FILE_GENERIC_WRITE,
+            FILE_READ_ATTRIBUTES, FILE_READ_DATA, FILE_READ_EA, FILE_TRAVERSE,
+            FILE_WRITE_ATTRIBUTES, FILE_WRITE_DATA, FILE_WRITE_EA, GENERIC_ALL, GENERIC_EXECUTE,
+            GENERIC_MAPPING, GENERIC_READ, GENERIC_WRITE, GROUP_SECURITY_INFORMATION,
+            INHERITED_ACE, INHERIT_ONLY_ACE, KEY_ALL_ACCESS, KEY_CREATE_LINK, KEY_CREATE_SUB_KEY,
+            KEY_ENUMERATE_SUB_KEYS, KEY_EXECUTE, KEY_NOTIFY, KEY_QUERY_VALUE, KEY_READ,
+            KEY_SET_VALUE, KEY_WRITE, LABEL_SECURITY_INFORMATION, LUID, LUID_AND_ATTRIBUTES,
+            MAXIMUM_ALLOWED, NO_PROPAGATE_INHERIT_ACE, OBJECT_INHERIT_ACE, OBJECT_TYPE_LIST,
+            OWNER_SECURITY_INFORMATION, PACL, PRIVILEGE_SET, PROTECTED_DACL_SECURITY_INFORMATION,
+            PROTECTED_SACL_SECURITY_INFORMATION, PSID, READ_CONTROL, SACL_SECURITY_INFORMATION,
+            SCOPE_SECURITY_INFORMATION, SECURITY_BUILTIN_DOMAIN_RID, SECURITY_DESCRIPTOR,
+            SECURITY_DESCRIPTOR_RELATIVE, SECURITY_DESCRIPTOR_REVISION,
+            SECURITY_MANDATORY_HIGH_RID, SECURITY_MANDATORY_LABEL_AUTHORITY,
+            SECURITY_MANDATORY_LOW_RID, SECURITY_MANDATORY_MEDIUM_RID,
+            SECURITY_MANDATORY_SYSTEM_RID, SECURITY_MANDATORY_UNTRUSTED_RID,
+            SECURITY_NT_NON_UNIQUE, SE_DACL_AUTO_INHERITED, SE_DACL_AUTO_INHERIT_REQ,
+            SE_DACL_DEFAULTED, SE_DACL_PRESENT, SE_DACL_PROTECTED, SE_GROUP_DEFAULTED,
+            SE_OWNER_DEFAULTED, SE_PRIVILEGE_ENABLED, SE_RM_CONTROL_VALID, SE_SACL_AUTO_INHERITED,
+            SE_SACL_AUTO_INHERIT_REQ, SE_SACL_DEFAULTED, SE_SACL_PRESENT, SE_SACL_PROTECTED,
+            SE_SECURITY_NAME, SE_SELF_RELATIVE, SID_IDENTIFIER_AUTHORITY, SID_MAX_SUB_AUTHORITIES,
+            SID_NAME_USE, SPECIFIC_RIGHTS_ALL, STANDARD_RIGHTS_ALL, STANDARD_RIGHTS_EXECUTE,
+            STANDARD_RIGHTS_READ, STANDARD_RIGHTS_REQUIRED, STANDARD_RIGHTS_WRITE,
+            SUCCESSFUL_ACCESS_ACE_FLAG, SYNCHRONIZE, SYSTEM_AUDIT_ACE, SYSTEM_AUDIT_ACE_TYPE,
+            SYSTEM_AUDIT_CALLBACK_ACE, SYSTEM_AUDIT_CALLBACK_ACE_TYPE,
+            SYSTEM_AUDIT_CALLBACK_OBJECT_ACE, SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE,
+            SYSTEM_AUDIT_OBJECT_ACE, SYSTEM_AUDIT_OBJECT_ACE_TYPE, SYSTEM_MANDATORY_LABEL_ACE,
+            SYSTEM_MANDATORY_LABEL_ACE_TYPE, SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP,
+            SYSTEM_MANDATORY_LABEL_NO_READ_UP, SYSTEM_MANDATORY_LABEL_NO_WRITE_UP,
+            SYSTEM_RESOURCE_ATTRIBUTE_ACE, SYSTEM_RESOURCE_ATTRIBUTE_ACE_TYPE,
+            SYSTEM_SCOPED_POLICY_ID_ACE, SYSTEM_SCOPED_POLICY_ID_ACE_TYPE, TOKEN_ADJUST_PRIVILEGES,
+            TOKEN_IMPERSONATE, TOKEN_PRIVILEGES, TOKEN_QUERY, TOKEN_USER,
+            UNPROTECTED_DACL_SECURITY_INFORMATION, UNPROTECTED_SACL_SECURITY_INFORMATION, WCHAR,
+            WRITE_DAC, WRITE_OWNER,
+        };
+        #[cfg(test)]
+        pub use super::super::all::{
+            WinBuiltinAdministratorsSid, WinCapabilityMusicLibrarySid, WinHighLabelSid,
+            WinLocalSid, WinLocalSystemSid, WinLowLabelSid, WinMediumLabelSid, WinWorldSid,
+            ACCESS_OBJECT_GUID, SE_CHANGE_NOTIFY_NAME, TOKEN_DUPLICATE,
+        };
+    }
+
+    pub mod winsvc {
+        pub use super::super::all::{
+            SERVICE_CHANGE_CONFIG, SERVICE_ENUMERATE_DEPENDENTS, SERVICE_INTERROGATE,
+            SERVICE_PAUSE_CONTINUE, SERVICE_QUERY_CONFIG, SERVICE_QUERY_STATUS, SERVICE_START,
+            SERVICE_STOP, SERVICE_USER_DEFINED_CONTROL,
+        };
+    }
+}
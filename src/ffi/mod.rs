@@ -0,0 +1,200 @@
+//! The raw Windows API, from the backend selected by cargo features
+//!
+//! Everything else in the crate reaches the Windows API through this module,
+//! using `winapi`'s module paths, names, and types. With the default `winapi`
+//! feature, those are simply `winapi`'s own modules. With the `windows-sys`
+//! feature, `compat` provides the same items on top of `windows-sys`, so
+//! that the rest of the crate is the same for both backends.
+
+#[cfg(feature = "windows-sys")]
+mod compat;
+
+#[cfg(feature = "windows-sys")]
+pub use self::compat::{ctypes, shared, um};
+#[cfg(not(feature = "windows-sys"))]
+pub use winapi::{ctypes, shared, um};
+
+use self::shared::guiddef::GUID;
+
+/// Build a `GUID` from its fields, which each backend names differently
+#[cfg(not(feature = "windows-sys"))]
+pub const fn guid(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> GUID {
+    GUID {
+        Data1: data1,
+        Data2: data2,
+        Data3: data3,
+        Data4: data4,
+    }
+}
+
+/// Build a `GUID` from its fields, which each backend names differently
+#[cfg(feature = "windows-sys")]
+pub const fn guid(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> GUID {
+    GUID {
+        data1,
+        data2,
+        data3,
+        data4,
+    }
+}
+
+/// Get the fields of a `GUID`, which each backend names differently
+#[cfg(not(feature = "windows-sys"))]
+pub fn guid_fields(guid: &GUID) -> (u32, u16, u16, [u8; 8]) {
+    (guid.Data1, guid.Data2, guid.Data3, guid.Data4)
+}
+
+/// Get the fields of a `GUID`, which each backend names differently
+#[cfg(feature = "windows-sys")]
+pub fn guid_fields(guid: &GUID) -> (u32, u16, u16, [u8; 8]) {
+    (guid.data1, guid.data2, guid.data3, guid.data4)
+}
+
+#[cfg(test)]
+mod test {
+    //! Both backends are dev-dependencies, so these tests compare the two
+    //! directly whichever one the crate is built with.
+
+    use crate::constants::SecurityInformation;
+    use crate::{LocalBox, SecurityDescriptor};
+    use std::ptr::null_mut;
+
+    /// An owner, a group, a protected DACL with inheritance and a deny ACE,
+    /// and a SACL
+    const SDDL: &str =
+        "O:BAG:SYD:PAI(A;OICI;FA;;;SY)(A;;0x1200a9;;;BU)(D;;WD;;;WD)S:(AU;SAFA;FA;;;WD)";
+
+    fn wide(s: &str) -> Vec<u16> {
+        s.encode_utf16().chain(Some(0)).collect()
+    }
+
+    /// Copy a string of `len` UTF-16 units that ends in a null
+    unsafe fn narrow(ptr: *const u16, len: u32) -> String {
+        let units = std::slice::from_raw_parts(ptr, len as usize);
+        String::from_utf16(&units[..units.len() - 1]).unwrap()
+    }
+
+    fn round_trip_winapi(sddl: &str) -> String {
+        use winapi::shared::sddl::{
+            ConvertSecurityDescriptorToStringSecurityDescriptorW,
+            ConvertStringSecurityDescriptorToSecurityDescriptorW, SDDL_REVISION_1,
+        };
+        use winapi::um::winbase::LocalFree;
+
+        let sddl = wide(sddl);
+        let mut sd = null_mut();
+        let mut string = null_mut();
+        let mut len = 0;
+
+        unsafe {
+            assert_ne!(
+                ConvertStringSecurityDescriptorToSecurityDescriptorW(
+                    sddl.as_ptr(),
+                    SDDL_REVISION_1.into(),
+                    &mut sd,
+                    null_mut(),
+                ),
+                0
+            );
+            assert_ne!(
+                ConvertSecurityDescriptorToStringSecurityDescriptorW(
+                    sd,
+                    SDDL_REVISION_1.into(),
+                    SecurityInformation::all().bits(),
+                    &mut string,
+                    &mut len,
+                ),
+                0
+            );
+
+            let result = narrow(string, len);
+            LocalFree(string as *mut _);
+            LocalFree(sd);
+            result
+        }
+    }
+
+    fn round_trip_windows_sys(sddl: &str) -> String {
+        use windows_sys::Win32::Foundation::LocalFree;
+        use windows_sys::Win32::Security::Authorization::{
+            ConvertSecurityDescriptorToStringSecurityDescriptorW,
+            ConvertStringSecurityDescriptorToSecurityDescriptorW, SDDL_REVISION_1,
+        };
+
+        let sddl = wide(sddl);
+        let mut sd = null_mut();
+        let mut string = null_mut();
+        let mut len = 0;
+
+        unsafe {
+            assert_ne!(
+                ConvertStringSecurityDescriptorToSecurityDescriptorW(
+                    sddl.as_ptr(),
+                    SDDL_REVISION_1,
+                    &mut sd,
+                    null_mut(),
+                ),
+                0
+            );
+            assert_ne!(
+                ConvertSecurityDescriptorToStringSecurityDescriptorW(
+                    sd,
+                    SDDL_REVISION_1,
+                    SecurityInformation::all().bits(),
+                    &mut string,
+                    &mut len,
+                ),
+                0
+            );
+
+            let result = narrow(string, len);
+            LocalFree(string as *mut _);
+            LocalFree(sd);
+            result
+        }
+    }
+
+    #[test]
+    fn backends_round_trip_identically() {
+        let through_winapi = round_trip_winapi(SDDL);
+        let through_windows_sys = round_trip_windows_sys(SDDL);
+
+        assert!(through_winapi.starts_with("O:BAG:SYD:PAI"));
+        assert_eq!(through_windows_sys, through_winapi);
+
+        let sd: LocalBox<SecurityDescriptor> = SDDL.parse().unwrap();
+        assert_eq!(sd.as_sddl().unwrap(), &*through_winapi);
+    }
+
+    #[test]
+    fn guid_fields_round_trip() {
+        let fields = (0x01234567, 0x89ab, 0xcdef, [1, 2, 3, 4, 5, 6, 7, 8]);
+        let guid = super::guid(fields.0, fields.1, fields.2, fields.3);
+
+        assert_eq!(super::guid_fields(&guid), fields);
+    }
+
+    #[test]
+    fn guid_pointer_reads_as_either_backend() {
+        let guid = crate::Guid::new(0x01234567, 0x89ab, 0xcdef, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let through_winapi = unsafe { &*(guid.as_ptr() as *const winapi::shared::guiddef::GUID) };
+        let through_windows_sys = unsafe { &*(guid.as_ptr() as *const windows_sys::core::GUID) };
+
+        assert_eq!(
+            (
+                through_winapi.Data1,
+                through_winapi.Data2,
+                through_winapi.Data3,
+                through_winapi.Data4
+            ),
+            (
+                through_windows_sys.data1,
+                through_windows_sys.data2,
+                through_windows_sys.data3,
+                through_windows_sys.data4
+            )
+        );
+        assert_eq!(through_winapi.Data1, 0x01234567);
+    }
+}
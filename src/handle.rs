@@ -0,0 +1,183 @@
+//! A handle to a Windows object, closed on drop when it is owned.
+
+use crate::constants::SecurityImpersonationLevel;
+use crate::wrappers;
+use std::fmt;
+use std::io;
+use std::os::windows::io::{AsHandle, AsRawHandle, BorrowedHandle, RawHandle};
+
+/// A Windows handle that knows whether it should be closed.
+///
+/// An owned `Handle` calls `CloseHandle` when it is dropped. A borrowed
+/// `Handle` refers to a handle that something else closes, and dropping it
+/// does nothing. Functions in this crate that take a token take a `Handle`;
+/// functions that take a handle to any kind of object take an
+/// [`AsRawHandle`], which `Handle` implements.
+///
+/// ```
+/// use windows_permissions::Handle;
+/// use winapi::um::winnt::TOKEN_QUERY;
+///
+/// let token = Handle::open_process_token(TOKEN_QUERY).unwrap();
+/// assert!(token.is_owned());
+///
+/// // Closed here
+/// drop(token);
+/// ```
+pub struct Handle {
+    raw: RawHandle,
+    owned: bool,
+}
+
+// Kernel handles can be used and closed from any thread
+unsafe impl Send for Handle {}
+unsafe impl Sync for Handle {}
+
+impl Handle {
+    /// Take ownership of a raw handle, which will be closed on drop
+    ///
+    /// # Safety
+    ///
+    /// - `raw` must be an open handle that can be closed with `CloseHandle`
+    /// - Nothing else may close `raw`
+    pub unsafe fn from_raw_owned(raw: RawHandle) -> Self {
+        Self { raw, owned: true }
+    }
+
+    /// Borrow a raw handle, which will not be closed on drop
+    ///
+    /// # Safety
+    ///
+    /// `raw` must stay open for as long as the `Handle` is used.
+    pub unsafe fn from_raw_borrowed(raw: RawHandle) -> Self {
+        Self { raw, owned: false }
+    }
+
+    /// Get the pseudo-handle for the current process
+    ///
+    /// The pseudo-handle is always valid in the current process and never
+    /// needs closing, so the `Handle` is borrowed.
+    pub fn current_process() -> Self {
+        unsafe {
+            Self::from_raw_borrowed(crate::ffi::um::processthreadsapi::GetCurrentProcess() as _)
+        }
+    }
+
+    /// Open the token of the current process
+    ///
+    /// `access` is a combination of the `TOKEN_*` access rights. This is
+    /// the same as [`wrappers::OpenProcessToken`].
+    pub fn open_process_token(access: u32) -> io::Result<Self> {
+        wrappers::OpenProcessToken(access)
+    }
+
+    /// Create an impersonation token at `level` from this token
+    ///
+    /// This token must have been opened with `TOKEN_DUPLICATE` access. This
+    /// is the same as [`wrappers::DuplicateToken`].
+    pub fn duplicate_token(&self, level: SecurityImpersonationLevel) -> io::Result<Self> {
+        wrappers::DuplicateToken(self, level)
+    }
+
+    /// Get the raw handle
+    ///
+    /// The raw handle is only valid for as long as this `Handle` is alive.
+    pub fn as_raw(&self) -> RawHandle {
+        self.raw
+    }
+
+    /// Check whether the handle is closed when this `Handle` is dropped
+    pub fn is_owned(&self) -> bool {
+        self.owned
+    }
+}
+
+#[cfg(test)]
+impl Handle {
+    /// Get an impersonation token for the current process, for checks that
+    /// need one
+    ///
+    /// Only built on `cfg(test)`.
+    pub fn impersonation_token() -> Self {
+        use crate::ffi::um::winnt::{TOKEN_DUPLICATE, TOKEN_QUERY};
+
+        Handle::open_process_token(TOKEN_DUPLICATE | TOKEN_QUERY)
+            .and_then(|token| {
+                token.duplicate_token(SecurityImpersonationLevel::SecurityImpersonation)
+            })
+            .expect("Could not get an impersonation token for the current process")
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        if self.owned {
+            let result = unsafe { crate::ffi::um::handleapi::CloseHandle(self.raw as _) };
+
+            // Fails if the handle was already closed
+            debug_assert_ne!(result, 0, "Failed to close handle: it was closed twice");
+        }
+    }
+}
+
+impl AsRawHandle for Handle {
+    fn as_raw_handle(&self) -> RawHandle {
+        self.raw
+    }
+}
+
+impl AsHandle for Handle {
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        unsafe { BorrowedHandle::borrow_raw(self.as_raw_handle()) }
+    }
+}
+
+impl fmt::Debug for Handle {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("Handle")
+            .field("raw", &self.raw)
+            .field("owned", &self.owned)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ffi::um::winnt::TOKEN_QUERY;
+
+    /// Check that `raw` is still an open handle
+    fn is_open(raw: RawHandle) -> bool {
+        let mut flags = 0u32;
+        unsafe { crate::ffi::um::handleapi::GetHandleInformation(raw as _, &mut flags) != 0 }
+    }
+
+    #[test]
+    fn open_and_drop_token() -> io::Result<()> {
+        let token = Handle::open_process_token(TOKEN_QUERY)?;
+        assert!(token.is_owned());
+        assert!(is_open(token.as_raw()));
+
+        // Dropping a borrowed copy must not close the handle, or dropping
+        // `token` would close it a second time and fail the debug assertion
+        let borrowed = unsafe { Handle::from_raw_borrowed(token.as_raw()) };
+        assert!(!borrowed.is_owned());
+        drop(borrowed);
+        assert!(is_open(token.as_raw()));
+
+        drop(token);
+
+        Ok(())
+    }
+
+    #[test]
+    fn current_process() {
+        let process = Handle::current_process();
+
+        assert!(!process.is_owned());
+        assert_eq!(process.as_raw(), unsafe {
+            crate::ffi::um::processthreadsapi::GetCurrentProcess() as RawHandle
+        });
+        assert_eq!(process.as_raw_handle(), process.as_handle().as_raw_handle());
+    }
+}
@@ -18,6 +18,23 @@
 //! - In the `wrappers` crate, there are safe versions of the Windows API functions.
 //!   Any Windows API function not implemented should be reported as an issue.
 //!
+//! ## Optional features
+//!
+//! - `tracing`: Instrument the Windows API calls in [`wrappers`] with
+//!   [`tracing`](https://docs.rs/tracing) spans and error events.
+//! - `schema`: Recognize common Active Directory schema GUIDs (object classes
+//!   and property sets) with `Guid::as_well_known_object`.
+//! - `rayon`: Read the security of a directory tree in parallel with
+//!   `tree::scan_tree_par`.
+//! - `windows-sys`: Call the Windows API through
+//!   [`windows-sys`](https://docs.rs/windows-sys) instead of
+//!   [`winapi`](https://docs.rs/winapi). To avoid depending on `winapi` at
+//!   all, also turn off the default `winapi` feature. The API of this crate
+//!   is the same with either backend: raw pointers, such as the one from
+//!   `Guid::as_ptr`, are `std::ffi::c_void` pointers, and raw values use
+//!   types defined by this crate, such as `Luid`. If both features are on,
+//!   `windows-sys` is used.
+//!
 //! ## Contributing
 //!
 //! PRs are happily accepted! In general, `unsafe` code should be confined to the
@@ -31,27 +48,46 @@
 
 #![deny(missing_docs)]
 
+#[cfg(target_os = "windows")]
 #[macro_use]
 extern crate bitflags;
-extern crate winapi;
+
+#[cfg(all(
+    target_os = "windows",
+    not(any(feature = "winapi", feature = "windows-sys"))
+))]
+compile_error!("Enable either the `winapi` or the `windows-sys` feature");
 
 #[cfg(target_os = "windows")]
 pub mod constants;
 #[cfg(target_os = "windows")]
+pub mod handle;
+#[cfg(target_os = "windows")]
 pub mod localheap;
 #[cfg(target_os = "windows")]
+pub mod sddl;
+#[cfg(target_os = "windows")]
 pub mod structures;
 #[cfg(target_os = "windows")]
+pub mod tree;
+#[cfg(target_os = "windows")]
 pub mod utilities;
 #[cfg(target_os = "windows")]
 pub mod wrappers;
 
+#[cfg(target_os = "windows")]
+mod ffi;
 #[cfg(target_os = "windows")]
 mod windows_secure;
 
+#[cfg(target_os = "windows")]
+pub use handle::Handle;
 #[cfg(target_os = "windows")]
 pub use localheap::LocalBox;
 #[cfg(target_os = "windows")]
-pub use structures::{Ace, Acl, SecurityDescriptor, Sid, Trustee};
+pub use structures::{
+    Ace, Acl, AclBuilder, GenericMapping, Guid, ObjectTypeListEntry, OwnedAcl, SecurityDescriptor,
+    Sid, Trustee,
+};
 #[cfg(target_os = "windows")]
 pub use windows_secure::WindowsSecure;
@@ -101,7 +101,7 @@ impl<T> LocalBox<T> {
             false => LocalAllocFlags::Fixed,
         };
 
-        let ptr = winapi::um::winbase::LocalAlloc(flags.bits(), size);
+        let ptr = crate::ffi::um::winbase::LocalAlloc(flags.bits(), size);
 
         Ok(Self {
             ptr: NonNull::new(ptr as *mut _).ok_or_else(io::Error::last_os_error)?,
@@ -118,7 +118,7 @@ impl<T> LocalBox<T> {
 
 impl<T> Drop for LocalBox<T> {
     fn drop(&mut self) {
-        let result = unsafe { winapi::um::winbase::LocalFree(self.as_ptr() as *mut _) };
+        let result = unsafe { crate::ffi::um::winbase::LocalFree(self.as_ptr() as *mut _) };
         debug_assert_eq!(result, null_mut());
     }
 }
@@ -186,6 +186,24 @@ where
     }
 }
 
+impl<T> PartialOrd for LocalBox<T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &LocalBox<T>) -> Option<std::cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<T> Ord for LocalBox<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &LocalBox<T>) -> std::cmp::Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
 // Safety: LocalAlloc/LocalFree are wrapper functions that call the
 // corresponding heap functions (HeapAlloc/HeapFree) using a handle to the
 // process default heap. The HeapAlloc documentation states "Serialization
@@ -0,0 +1,454 @@
+//! Diagnostics for Security Descriptor Definition Language (SDDL) strings.
+//!
+//! Windows only reports that an SDDL string is invalid, not where. The
+//! functions here run the real parser and, if it fails, scan the string to
+//! find the component that is most likely at fault. [`spans`] finds where
+//! each part of a valid string is.
+
+use crate::wrappers;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::ops::Range;
+
+/// The broad category of an [`SddlError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SddlErrorKind {
+    /// A parenthesis is never closed, or is closed without being opened
+    UnbalancedParentheses,
+
+    /// An ACE type, ACE flag, access right, or SID alias is not recognized
+    UnknownMnemonic,
+
+    /// Windows rejected the string, but the cause could not be narrowed down
+    Invalid,
+}
+
+/// A description of why an SDDL string could not be parsed.
+#[derive(Debug)]
+pub struct SddlError {
+    /// The category of the error
+    pub kind: SddlErrorKind,
+
+    /// A human-readable description, including the Windows error
+    pub message: String,
+
+    /// The byte offset of the offending component, if it could be found
+    pub offset: Option<usize>,
+
+    source: io::Error,
+}
+
+impl SddlError {
+    /// Get the error reported by Windows when parsing the string
+    pub fn os_error(&self) -> &io::Error {
+        &self.source
+    }
+}
+
+impl fmt::Display for SddlError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.offset {
+            Some(offset) => write!(fmt, "{} at offset {}", self.message, offset),
+            None => write!(fmt, "{}", self.message),
+        }
+    }
+}
+
+impl Error for SddlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Check whether an SDDL string can be parsed into a security descriptor
+///
+/// This is the implementation of
+/// [`SecurityDescriptor::validate_sddl`](crate::SecurityDescriptor::validate_sddl).
+///
+/// ```
+/// use windows_permissions::sddl::{validate, SddlErrorKind};
+///
+/// assert!(validate("O:SYD:(A;;FA;;;WD)").is_ok());
+///
+/// let err = validate("D:(A;;FA;;;WD").unwrap_err();
+/// assert_eq!(err.kind, SddlErrorKind::UnbalancedParentheses);
+/// assert_eq!(err.offset, Some(2));
+/// ```
+pub fn validate(sddl: &str) -> Result<(), SddlError> {
+    let os_error = match wrappers::ConvertStringSecurityDescriptorToSecurityDescriptor(sddl) {
+        Ok(_) => return Ok(()),
+        Err(e) => e,
+    };
+
+    let (kind, detail, offset) = match diagnose(sddl) {
+        Some((kind, detail, offset)) => (kind, detail, Some(offset)),
+        None => (SddlErrorKind::Invalid, "invalid SDDL".to_string(), None),
+    };
+
+    Err(SddlError {
+        kind,
+        message: format!("{} ({})", detail, os_error),
+        offset,
+        source: os_error,
+    })
+}
+
+/// The byte ranges of the sections of an SDDL string.
+///
+/// Each range covers a whole section, including its `O:`, `G:`, `D:` or `S:`
+/// marker, and is `None` if the section is not in the string.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SddlSpans {
+    /// The `O:` section
+    pub owner: Option<Range<usize>>,
+
+    /// The `G:` section
+    pub group: Option<Range<usize>>,
+
+    /// The `D:` section, including its flags and ACEs
+    pub dacl: Option<Range<usize>>,
+
+    /// The `S:` section, including its flags and ACEs
+    pub sacl: Option<Range<usize>>,
+}
+
+/// Find the byte range of each section of an SDDL string
+///
+/// This is the implementation of
+/// [`SecurityDescriptor::sddl_spans`](crate::SecurityDescriptor::sddl_spans).
+/// The string is checked with [`validate`] first, and an invalid string gives
+/// an error of kind [`io::ErrorKind::InvalidInput`].
+///
+/// ```
+/// use windows_permissions::sddl::spans;
+///
+/// let sddl = "O:SYD:(A;;FA;;;WD)";
+/// let spans = spans(sddl).unwrap();
+///
+/// assert_eq!(&sddl[spans.owner.unwrap()], "O:SY");
+/// assert_eq!(&sddl[spans.dacl.unwrap()], "D:(A;;FA;;;WD)");
+/// assert_eq!(spans.group, None);
+/// ```
+pub fn spans(sddl: &str) -> io::Result<SddlSpans> {
+    validate(sddl).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let mut spans = SddlSpans::default();
+
+    for (marker, start, end) in sections(sddl) {
+        // `sections` gives the range after the two-byte marker
+        let span = Some(start - 2..end);
+
+        match marker {
+            'O' => spans.owner = span,
+            'G' => spans.group = span,
+            'D' => spans.dacl = span,
+            _ => spans.sacl = span,
+        }
+    }
+
+    Ok(spans)
+}
+
+const ACE_TYPES: &[&str] = &[
+    "A", "D", "AU", "AL", "OA", "OD", "OU", "OL", "ML", "XA", "XD", "XU", "ZA", "RA", "SP", "TL",
+    "FL",
+];
+
+const ACE_FLAGS: &[&str] = &["CI", "OI", "NP", "IO", "ID", "SA", "FA", "TP", "CR"];
+
+const ACCESS_RIGHTS: &[&str] = &[
+    "GA", "GR", "GW", "GX", "RC", "SD", "WD", "WO", "RP", "WP", "CC", "DC", "LC", "SW", "LO", "DT",
+    "CR", "FA", "FR", "FW", "FX", "KA", "KR", "KW", "KX", "NR", "NW", "NX",
+];
+
+const SID_ALIASES: &[&str] = &[
+    "AA", "AC", "AN", "AO", "AP", "AS", "AU", "BA", "BG", "BO", "BU", "CA", "CD", "CG", "CN", "CO",
+    "CY", "DA", "DC", "DD", "DG", "DU", "EA", "ED", "EK", "ER", "ES", "HA", "HI", "IS", "IU", "KA",
+    "LA", "LG", "LS", "LU", "LW", "ME", "MP", "MU", "NO", "NS", "NU", "OW", "PA", "PO", "PS", "PU",
+    "RA", "RC", "RD", "RE", "RM", "RO", "RS", "RU", "SA", "SI", "SO", "SP", "SS", "SU", "SY", "UD",
+    "WD", "WR",
+];
+
+type Diagnosis = (SddlErrorKind, String, usize);
+
+/// Find the most likely cause of a parse failure
+fn diagnose(sddl: &str) -> Option<Diagnosis> {
+    check_parentheses(sddl).or_else(|| check_mnemonics(sddl))
+}
+
+/// Find the first unbalanced parenthesis, ignoring any inside quotes
+fn check_parentheses(sddl: &str) -> Option<Diagnosis> {
+    let mut open = Vec::new();
+    let mut in_quotes = false;
+
+    for (offset, c) in sddl.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '(' if !in_quotes => open.push(offset),
+            // Popping in the guard closes the matching '(' when there is one
+            ')' if !in_quotes && open.pop().is_none() => {
+                return Some((
+                    SddlErrorKind::UnbalancedParentheses,
+                    "unexpected ')'".to_string(),
+                    offset,
+                ));
+            }
+            _ => (),
+        }
+    }
+
+    open.first().map(|&offset| {
+        (
+            SddlErrorKind::UnbalancedParentheses,
+            "unclosed '('".to_string(),
+            offset,
+        )
+    })
+}
+
+/// Find the first unrecognized mnemonic in the owner, group, or ACEs
+///
+/// Assumes the parentheses are balanced.
+fn check_mnemonics(sddl: &str) -> Option<Diagnosis> {
+    for (marker, start, end) in sections(sddl) {
+        let section = &sddl[start..end];
+
+        let found = match marker {
+            'O' | 'G' => check_sid(section, start),
+            _ => aces(section, start).find_map(|(ace, offset)| check_ace(ace, offset)),
+        };
+
+        if found.is_some() {
+            return found;
+        }
+    }
+
+    None
+}
+
+/// Split the string into its `O:`, `G:`, `D:` and `S:` sections
+///
+/// Yields the section marker and the byte range of the section's contents.
+fn sections(sddl: &str) -> Vec<(char, usize, usize)> {
+    let bytes = sddl.as_bytes();
+    let mut markers = Vec::new();
+    let mut depth = 0usize;
+
+    for (i, &b) in bytes.iter().enumerate() {
+        match b {
+            b'(' => depth += 1,
+            b')' => depth = depth.saturating_sub(1),
+            b':' if depth == 0 && i > 0 && b"OGDS".contains(&bytes[i - 1]) => {
+                markers.push((bytes[i - 1] as char, i - 1))
+            }
+            _ => (),
+        }
+    }
+
+    markers
+        .iter()
+        .enumerate()
+        .map(|(n, &(marker, at))| {
+            let end = markers.get(n + 1).map_or(sddl.len(), |&(_, next)| next);
+            (marker, at + 2, end)
+        })
+        .collect()
+}
+
+/// Iterate over the contents of each top-level `(...)` group in an ACL section
+fn aces(section: &str, base: usize) -> impl Iterator<Item = (&str, usize)> {
+    let mut groups = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, c) in section.char_indices() {
+        match c {
+            '(' => {
+                if depth == 0 {
+                    start = i + 1;
+                }
+                depth += 1;
+            }
+            ')' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    groups.push((&section[start..i], base + start));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    groups.into_iter()
+}
+
+/// Check the fields of a single ACE string
+fn check_ace(ace: &str, base: usize) -> Option<Diagnosis> {
+    let mut offset = base;
+    let fields: Vec<(&str, usize)> = ace
+        .splitn(7, ';')
+        .map(|field| {
+            let start = offset;
+            offset += field.len() + 1;
+            (field, start)
+        })
+        .collect();
+
+    let (ace_type, type_offset) = fields[0];
+    if !ACE_TYPES.contains(&ace_type) {
+        return Some(unknown("ACE type", ace_type, type_offset));
+    }
+
+    if let Some(&(flags, flags_offset)) = fields.get(1) {
+        if let Some(found) = check_pairs(flags, flags_offset, ACE_FLAGS, "ACE flag") {
+            return Some(found);
+        }
+    }
+
+    if let Some(&(rights, rights_offset)) = fields.get(2) {
+        let numeric = rights.starts_with("0x") || rights.starts_with("0X");
+        let decimal = !rights.is_empty() && rights.bytes().all(|b| b.is_ascii_digit());
+        if !numeric && !decimal {
+            if let Some(found) = check_pairs(rights, rights_offset, ACCESS_RIGHTS, "access right") {
+                return Some(found);
+            }
+        }
+    }
+
+    if let Some(&(sid, sid_offset)) = fields.get(5) {
+        if let Some(found) = check_sid(sid, sid_offset) {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+/// Check a string made of two-letter mnemonics against a list
+fn check_pairs(text: &str, base: usize, known: &[&str], what: &str) -> Option<Diagnosis> {
+    if !text.is_ascii() {
+        return Some(unknown(what, text, base));
+    }
+
+    let mut offset = 0;
+
+    while offset < text.len() {
+        let end = (offset + 2).min(text.len());
+        let pair = &text[offset..end];
+
+        if !known.contains(&pair) {
+            return Some(unknown(what, pair, base + offset));
+        }
+
+        offset = end;
+    }
+
+    None
+}
+
+/// Check a SID field, which is either empty, a string SID, or an alias
+fn check_sid(sid: &str, base: usize) -> Option<Diagnosis> {
+    if sid.is_empty() || sid.starts_with("S-") || SID_ALIASES.contains(&sid) {
+        None
+    } else {
+        Some(unknown("SID", sid, base))
+    }
+}
+
+fn unknown(what: &str, mnemonic: &str, offset: usize) -> Diagnosis {
+    (
+        SddlErrorKind::UnknownMnemonic,
+        format!("unknown {} \"{}\"", what, mnemonic),
+        offset,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        let valid = [
+            "O:SYG:BA",
+            "O:SYG:SYD:(A;;FA;;;WD)",
+            "D:PAI(A;OICI;FA;;;SY)(A;;0x1200a9;;;S-1-5-21-1-2-3-1001)",
+            "D:(XA;;FR;;;WD;(Member_of {SID(BA)}))",
+            "S:(AU;SAFA;FA;;;WD)",
+        ];
+
+        for sddl in valid.iter() {
+            assert!(validate(sddl).is_ok(), "{} failed to validate", sddl);
+        }
+    }
+
+    #[test]
+    fn unbalanced_parentheses() {
+        let err = validate("O:SYD:(A;;FA;;;WD)(A;;FR;;;BU").unwrap_err();
+        assert_eq!(err.kind, SddlErrorKind::UnbalancedParentheses);
+        assert_eq!(err.offset, Some(18));
+
+        let err = validate("D:(A;;FA;;;WD))").unwrap_err();
+        assert_eq!(err.kind, SddlErrorKind::UnbalancedParentheses);
+        assert_eq!(err.offset, Some(14));
+    }
+
+    #[test]
+    fn unknown_mnemonic() {
+        let cases = [
+            ("D:(A;;QQ;;;WD)", 6, "QQ"),
+            ("D:(Q;;FA;;;WD)", 3, "Q"),
+            ("D:(A;XX;FA;;;WD)", 5, "XX"),
+            ("D:(A;;FA;;;ZZ)", 11, "ZZ"),
+            ("O:ZZD:(A;;FA;;;WD)", 2, "ZZ"),
+            ("D:(A;;FA;;;WD)(A;;FRQQ;;;WD)", 20, "QQ"),
+        ];
+
+        for (sddl, offset, mnemonic) in cases.iter() {
+            let err = validate(sddl).unwrap_err();
+
+            assert_eq!(err.kind, SddlErrorKind::UnknownMnemonic, "{}", sddl);
+            assert_eq!(err.offset, Some(*offset), "{}", sddl);
+            assert!(err.message.contains(mnemonic), "{}", err);
+            assert!(err.os_error().raw_os_error().is_some());
+        }
+    }
+
+    #[test]
+    fn kinds_are_distinct() {
+        let unbalanced = validate("D:(A;;FA;;;WD").unwrap_err();
+        let unknown = validate("D:(A;;QQ;;;WD)").unwrap_err();
+
+        assert_ne!(unbalanced.kind, unknown.kind);
+    }
+
+    #[test]
+    fn section_spans() -> io::Result<()> {
+        let sddl = "O:SYG:SYD:(A;;;;;WD)S:(AU;;;;;WD)";
+        let found = spans(sddl)?;
+
+        assert_eq!(found.owner, Some(0..4));
+        assert_eq!(found.group, Some(4..8));
+        assert_eq!(found.dacl, Some(8..20));
+        assert_eq!(found.sacl, Some(20..sddl.len()));
+
+        assert_eq!(&sddl[found.owner.unwrap()], "O:SY");
+        assert_eq!(&sddl[found.group.unwrap()], "G:SY");
+        assert_eq!(&sddl[found.dacl.unwrap()], "D:(A;;;;;WD)");
+        assert_eq!(&sddl[found.sacl.unwrap()], "S:(AU;;;;;WD)");
+
+        // Sections can come in any order, and any of them can be missing
+        let partial = "D:(A;;FA;;;WD)G:BA";
+        let found = spans(partial)?;
+        assert_eq!(&partial[found.dacl.unwrap()], "D:(A;;FA;;;WD)");
+        assert_eq!(&partial[found.group.unwrap()], "G:BA");
+        assert_eq!(found.owner, None);
+        assert_eq!(found.sacl, None);
+
+        let err = spans("D:(A;;FA;;;WD").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        Ok(())
+    }
+}
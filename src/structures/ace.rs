@@ -1,9 +1,10 @@
 use crate::constants::{AccessRights, AceFlags, AceType};
-use crate::Sid;
+use crate::ffi::um::winnt::ACE_HEADER;
+use crate::{wrappers, Sid};
+use std::ffi::c_void;
 use std::fmt;
 use std::mem;
 use std::ptr::NonNull;
-use winapi::um::winnt::ACE_HEADER;
 
 /// An access control list.
 ///
@@ -29,7 +30,7 @@ impl Ace {
     /// - `ptr` must point to a valid ACE structure
     /// - The ACE header must be followed by the correct ACE structure
     /// - The entire structure must remain alive at least as long as `'s`
-    pub unsafe fn ref_from_nonnull<'s>(ptr: NonNull<ACE_HEADER>) -> &'s Self {
+    pub unsafe fn ref_from_nonnull<'s>(ptr: NonNull<c_void>) -> &'s Self {
         mem::transmute(ptr)
     }
 
@@ -38,6 +39,30 @@ impl Ace {
         AceType::from_raw(self.header.AceType).expect("ACE had invalid header byte")
     }
 
+    /// Get the total size of the ACE in bytes, including the header
+    pub fn size(&self) -> u16 {
+        self.header.AceSize
+    }
+
+    /// Get the raw bytes of the ACE, including the header
+    ///
+    /// The slice is [`size`](Self::size) bytes long and borrows from the ACE,
+    /// so it lives only as long as the ACL the ACE belongs to.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    /// use windows_permissions::constants::AceType;
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)".parse().unwrap();
+    /// let ace = sd.dacl().unwrap().get_ace(0).unwrap();
+    ///
+    /// assert_eq!(ace.as_bytes().len(), ace.size() as usize);
+    /// assert_eq!(ace.as_bytes()[0], AceType::ACCESS_ALLOWED_ACE_TYPE as u8);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self as *const _ as *const u8, self.size() as usize) }
+    }
+
     /// Get the option flags set on the ACE
     pub fn flags(&self) -> AceFlags {
         debug_assert!(AceFlags::from_bits(self.header.AceFlags).is_some());
@@ -46,7 +71,7 @@ impl Ace {
 
     /// Get the access mask if it is available for this ACE type
     pub fn mask(&self) -> AccessRights {
-        use winapi::um::winnt::*;
+        use crate::ffi::um::winnt::*;
 
         macro_rules! mask_mapping {
             ($slf:ident ; $($t:ident => $b:ty),*) => {{
@@ -72,7 +97,7 @@ impl Ace {
                 ACCESS_DENIED_OBJECT_ACE_TYPE => ACCESS_DENIED_OBJECT_ACE,
                 SYSTEM_AUDIT_ACE_TYPE => SYSTEM_AUDIT_ACE,
                 SYSTEM_AUDIT_CALLBACK_ACE_TYPE => SYSTEM_AUDIT_CALLBACK_ACE,
-                SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE => SYSTEM_AUDIT_CALLBACK_ACE,
+                SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE => SYSTEM_AUDIT_CALLBACK_OBJECT_ACE,
                 SYSTEM_AUDIT_OBJECT_ACE_TYPE => SYSTEM_AUDIT_OBJECT_ACE,
                 SYSTEM_MANDATORY_LABEL_ACE_TYPE => SYSTEM_MANDATORY_LABEL_ACE,
                 SYSTEM_RESOURCE_ATTRIBUTE_ACE_TYPE => SYSTEM_RESOURCE_ATTRIBUTE_ACE,
@@ -83,7 +108,7 @@ impl Ace {
 
     /// Get the SID if it is available for this ACE type
     pub fn sid(&self) -> Option<&Sid> {
-        use winapi::um::winnt::*;
+        use crate::ffi::um::winnt::*;
 
         macro_rules! get_sid {
             ($slf:ident ; $ace_type:ty ; $sid_field:ident ) => {
@@ -97,8 +122,8 @@ impl Ace {
             };
             ($slf:ident ; $ace_type:ty ; $field_none:ident , $field_one:ident, $field_both:ident) => {{
                 let flags = (*(&$slf.header as *const ACE_HEADER as *const $ace_type)).Flags;
-                let obj_pres = flags & winapi::um::winnt::ACE_OBJECT_TYPE_PRESENT != 0;
-                let inh_pres = flags & winapi::um::winnt::ACE_INHERITED_OBJECT_TYPE_PRESENT != 0;
+                let obj_pres = flags & crate::ffi::um::winnt::ACE_OBJECT_TYPE_PRESENT != 0;
+                let inh_pres = flags & crate::ffi::um::winnt::ACE_INHERITED_OBJECT_TYPE_PRESENT != 0;
                 match (obj_pres, inh_pres) {
                     (false, false) => get_sid!($slf ; $ace_type ; $field_none),
                     (true,  false) => get_sid!($slf ; $ace_type ; $field_one),
@@ -135,7 +160,8 @@ impl Ace {
                     get_sid!(self; SYSTEM_AUDIT_CALLBACK_ACE)
                 }
                 AceType::SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE => {
-                    get_sid!(self; SYSTEM_AUDIT_CALLBACK_ACE; SidStart)
+                    get_sid!(self; SYSTEM_AUDIT_CALLBACK_OBJECT_ACE;
+                    ObjectType, InheritedObjectType, SidStart)
                 }
                 AceType::SYSTEM_AUDIT_OBJECT_ACE_TYPE => get_sid!(self; SYSTEM_AUDIT_OBJECT_ACE;
                     ObjectType, InheritedObjectType, SidStart),
@@ -149,6 +175,56 @@ impl Ace {
             }
         }
     }
+
+    /// Get the application data of a callback ACE
+    ///
+    /// For callback ACE types (such as those created by the `XA`, `XD`, `XU`
+    /// and `ZA` SDDL ACE strings), this is the data following the SID. For
+    /// conditional ACEs it holds the binary conditional expression, which
+    /// starts with the `artx` signature and may be padded with zeros up to
+    /// the end of the ACE. Returns `None` for all other ACE types.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    /// use windows_permissions::constants::AceType;
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> =
+    ///     "D:(XA;;FR;;;WD;(Member_of {SID(BA)}))".parse().unwrap();
+    /// let ace = sd.dacl().unwrap().get_ace(0).unwrap();
+    ///
+    /// assert_eq!(ace.ace_type(), AceType::ACCESS_ALLOWED_CALLBACK_ACE_TYPE);
+    /// assert!(ace.condition().unwrap().starts_with(b"artx"));
+    /// ```
+    pub fn condition(&self) -> Option<&[u8]> {
+        match self.ace_type() {
+            AceType::ACCESS_ALLOWED_CALLBACK_ACE_TYPE
+            | AceType::ACCESS_ALLOWED_CALLBACK_OBJECT_ACE_TYPE
+            | AceType::ACCESS_DENIED_CALLBACK_ACE_TYPE
+            | AceType::ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE
+            | AceType::SYSTEM_AUDIT_CALLBACK_ACE_TYPE
+            | AceType::SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE => {}
+            _ => return None,
+        }
+
+        let sid = self.sid()?;
+        let ace_start = &self.header as *const ACE_HEADER as usize;
+        let data_start =
+            sid as *const Sid as usize + wrappers::GetSidLengthRequired(sid.sub_authority_count());
+        let ace_end = ace_start + self.size() as usize;
+
+        if data_start > ace_end {
+            return None;
+        }
+
+        // The application data runs from the end of the SID to the end of
+        // the ACE, which is `AceSize` bytes after the header
+        unsafe {
+            Some(std::slice::from_raw_parts(
+                data_start as *const u8,
+                ace_end - data_start,
+            ))
+        }
+    }
 }
 
 impl fmt::Debug for Ace {
@@ -172,6 +248,30 @@ mod test {
 
     use DaclSacl::{Dacl, Sacl};
 
+    #[test]
+    fn as_bytes() {
+        let sd: LocalBox<SecurityDescriptor> =
+            "D:(A;CI;FA;;;SY)(D;;FW;;;S-1-5-21-1-2-3-1001)S:(AU;FA;FR;;;WD)(ML;;NW;;;HI)"
+                .parse()
+                .unwrap();
+
+        for acl in [sd.dacl().unwrap(), sd.sacl().unwrap()].iter() {
+            for ace in acl.aces() {
+                let bytes = ace.as_bytes();
+
+                assert_eq!(bytes.len(), ace.size() as usize);
+                assert_eq!(bytes[0], ace.ace_type() as u8);
+                assert_eq!(bytes[1], ace.flags().bits());
+                assert_eq!(u16::from_le_bytes([bytes[2], bytes[3]]), ace.size());
+
+                // These ACE types are a header, a mask, and a SID
+                let sid = ace.sid().unwrap();
+                let sid_len = wrappers::GetSidLengthRequired(sid.sub_authority_count());
+                assert_eq!(bytes.len(), 8 + sid_len);
+            }
+        }
+    }
+
     #[test]
     fn mandatory_label() {
         let access_rights = [
@@ -181,9 +281,9 @@ mod test {
         ];
 
         let test_setups = [
-            ("(ML;;", ";;;LW)", winapi::um::winnt::WinLowLabelSid),
-            ("(ML;;", ";;;ME)", winapi::um::winnt::WinMediumLabelSid),
-            ("(ML;;", ";;;HI)", winapi::um::winnt::WinHighLabelSid),
+            ("(ML;;", ";;;LW)", crate::ffi::um::winnt::WinLowLabelSid),
+            ("(ML;;", ";;;ME)", crate::ffi::um::winnt::WinMediumLabelSid),
+            ("(ML;;", ";;;HI)", crate::ffi::um::winnt::WinHighLabelSid),
         ];
 
         for (sddl1, sddl2, sid_type) in test_setups.iter() {
@@ -204,6 +304,62 @@ mod test {
         }
     }
 
+    #[test]
+    fn conditional_ace() {
+        let sd: LocalBox<SecurityDescriptor> =
+            "D:(XA;;FR;;;WD;(Member_of {SID(BA)}))".parse().unwrap();
+        let ace = sd.dacl().unwrap().get_ace(0).unwrap();
+
+        assert_eq!(ace.ace_type(), AceType::ACCESS_ALLOWED_CALLBACK_ACE_TYPE);
+        assert_eq!(ace.mask(), AccessRights::FileGenericRead);
+
+        let condition = ace.condition().unwrap();
+        assert!(!condition.is_empty());
+        assert!(condition.starts_with(b"artx"));
+        assert_eq!(ace.size() as usize % 4, 0);
+
+        // Non-callback ACEs carry no application data
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FR;;;WD)".parse().unwrap();
+        let ace = sd.dacl().unwrap().get_ace(0).unwrap();
+        assert_eq!(ace.condition(), None);
+    }
+
+    #[test]
+    fn audit_callback_object_ace() {
+        // SDDL has no string for SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE, so
+        // take a ZA ACE, which has the same layout, and change its type
+        let sd: LocalBox<SecurityDescriptor> = "D:(ZA;;FR;c434c045-9b91-4504-a2a0-aea9e781ec69;\
+            c434c045-9b91-4504-a2a0-aea9e781ec69;WD;(Member_of {SID(BA)}))"
+            .parse()
+            .unwrap();
+        let bytes = sd.dacl().unwrap().get_ace(0).unwrap().as_bytes();
+
+        // Aligned copy of the ACE
+        let mut buffer = vec![0u32; bytes.len().div_ceil(4)];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                buffer.as_mut_ptr() as *mut u8,
+                bytes.len(),
+            );
+        }
+        let ace = unsafe {
+            let ptr = NonNull::new(buffer.as_mut_ptr() as *mut ACE_HEADER).unwrap();
+            (*ptr.as_ptr()).AceType = AceType::SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE as u8;
+            Ace::ref_from_nonnull(ptr.cast())
+        };
+
+        let everyone: LocalBox<Sid> = "WD".parse().unwrap();
+
+        assert_eq!(
+            ace.ace_type(),
+            AceType::SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE
+        );
+        assert_eq!(ace.mask(), AccessRights::FileGenericRead);
+        assert_eq!(ace.sid(), Some(&*everyone));
+        assert!(ace.condition().unwrap().starts_with(b"artx"));
+    }
+
     #[test]
     fn resource_attribute() {
         // These are weird enough that they get their own tests
@@ -1,8 +1,12 @@
-use crate::{constants, wrappers, Ace, Trustee};
+use crate::ffi::shared::winerror::{ERROR_INVALID_PARAMETER, ERROR_NONE_MAPPED};
+use crate::ffi::um::winnt::ACL;
+use crate::{constants, wrappers, Ace, LocalBox, OwnedAcl, Sid, Trustee};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::io;
-use winapi::shared::winerror::ERROR_INVALID_PARAMETER;
-use winapi::um::winnt::ACL;
+use std::mem;
 
 /// An entry in an access control list (ACL).
 #[repr(C)]
@@ -17,6 +21,9 @@ impl Acl {
 
     /// Determine what rights the given `Trustee` has under this ACL
     ///
+    /// `trustee` can be anything that converts into a [`Trustee`]: a `&Sid`,
+    /// an account name as a `&str`, or a reference to an existing `Trustee`.
+    ///
     /// ```
     /// use windows_permissions::{LocalBox, Trustee, Sid, SecurityDescriptor};
     /// use windows_permissions::constants::AccessRights;
@@ -34,9 +41,13 @@ impl Acl {
     ///
     /// assert_eq!(acl.effective_rights(&trustee1).unwrap(), AccessRights::FileAllAccess);
     /// assert_eq!(acl.effective_rights(&trustee2).unwrap(), AccessRights::FileGenericRead);
+    /// assert_eq!(acl.effective_rights(&*sid2).unwrap(), AccessRights::FileGenericRead);
     /// ```
-    pub fn effective_rights(&self, trustee: &Trustee) -> io::Result<constants::AccessRights> {
-        wrappers::GetEffectiveRightsFromAcl(self, trustee)
+    pub fn effective_rights<'t>(
+        &self,
+        trustee: impl Into<Trustee<'t>>,
+    ) -> io::Result<constants::AccessRights> {
+        wrappers::GetEffectiveRightsFromAcl(self, &trustee.into())
     }
 
     /// Determine the number of ACEs in this ACL
@@ -53,7 +64,7 @@ impl Acl {
     pub fn len(&self) -> u32 {
         wrappers::GetAclInformationSize(self)
             .expect("GetAclInformation failed on valid ACL")
-            .AceCount
+            .ace_count
     }
 
     /// Get an ACE by index
@@ -92,6 +103,58 @@ impl Acl {
         }
     }
 
+    /// Iterate over the ACEs in this ACL, in order
+    ///
+    /// `&Acl` also implements `IntoIterator`, so an ACL can be used directly in
+    /// a `for` loop.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    /// use windows_permissions::constants::AceType;
+    ///
+    /// let sd = "D:(A;;FA;;;SY)(D;;FW;;;BG)(A;;FR;;;WD)"
+    ///     .parse::<LocalBox<SecurityDescriptor>>().unwrap();
+    /// let acl = sd.dacl().unwrap();
+    ///
+    /// let allowed = acl
+    ///     .aces()
+    ///     .filter(|ace| ace.ace_type() == AceType::ACCESS_ALLOWED_ACE_TYPE)
+    ///     .count();
+    ///
+    /// assert_eq!(allowed, 2);
+    /// ```
+    pub fn aces(&self) -> AceIter<'_> {
+        AceIter {
+            acl: self,
+            next: 0,
+            len: self.len(),
+        }
+    }
+
+    /// Count the ACEs in this ACL by type
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    /// use windows_permissions::constants::AceType::*;
+    ///
+    /// let sd = "D:(A;;FA;;;SY)(D;;FW;;;BG)(A;;FR;;;WD)"
+    ///     .parse::<LocalBox<SecurityDescriptor>>().unwrap();
+    /// let counts = sd.dacl().unwrap().type_counts();
+    ///
+    /// assert_eq!(counts.get(&ACCESS_ALLOWED_ACE_TYPE), Some(&2));
+    /// assert_eq!(counts.get(&ACCESS_DENIED_ACE_TYPE), Some(&1));
+    /// assert_eq!(counts.get(&SYSTEM_AUDIT_ACE_TYPE), None);
+    /// ```
+    pub fn type_counts(&self) -> BTreeMap<constants::AceType, u32> {
+        let mut counts = BTreeMap::new();
+
+        for ace in self {
+            *counts.entry(ace.ace_type()).or_insert(0) += 1;
+        }
+
+        counts
+    }
+
     /// Get the ACL's revision level
     ///
     /// ```
@@ -108,6 +171,237 @@ impl Acl {
         constants::AclRevision::from_raw(self.internal_type_reference().AclRevision)
             .expect("Unknown revision level")
     }
+
+    /// Get the raw bytes of the ACL
+    ///
+    /// This covers the whole ACL buffer, as given by the `AclSize` header
+    /// field, including any free space after the last ACE.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;WD)".parse().unwrap();
+    /// let bytes = sd.dacl().unwrap().as_bytes();
+    ///
+    /// // The ACL size is stored in bytes 2 and 3 of the header
+    /// assert_eq!(u16::from_le_bytes([bytes[2], bytes[3]]) as usize, bytes.len());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        let size = self.internal_type_reference().AclSize as usize;
+        unsafe { std::slice::from_raw_parts(self as *const _ as *const u8, size) }
+    }
+
+    /// Parse an ACL from raw bytes, such as those from [`as_bytes`](Self::as_bytes)
+    ///
+    /// The bytes are copied into a new [`OwnedAcl`]. They do not have to be
+    /// trusted: the header and every ACE are bounds-checked before they are
+    /// used, and an ACL that does not fit in `bytes` or fails validation gives
+    /// an error of kind [`io::ErrorKind::InvalidData`]. Bytes after the end
+    /// of the ACL are ignored.
+    ///
+    /// ```
+    /// use windows_permissions::{Acl, LocalBox, SecurityDescriptor};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)".parse().unwrap();
+    /// let bytes = sd.dacl().unwrap().as_bytes().to_vec();
+    ///
+    /// let acl = Acl::from_bytes(&bytes).unwrap();
+    /// assert_eq!(acl.len(), 1);
+    ///
+    /// assert!(Acl::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<OwnedAcl> {
+        OwnedAcl::copy_from_bytes(bytes)
+    }
+
+    /// Hash the set of permissions in this ACL, ignoring ACE order
+    ///
+    /// Only the type, access mask, and SID of each ACE are hashed, taken as
+    /// a sorted set, so ACLs that differ only in the order of their ACEs (or
+    /// in repeated ACEs) hash the same. ACE flags, including the inheritance
+    /// flags, are not part of the hash. This is for detecting permission
+    /// drift after an ACL has been reordered; hash [`as_bytes`](Self::as_bytes)
+    /// to compare ACLs exactly.
+    ///
+    /// The hash uses the standard library's default hasher, so it should not
+    /// be stored and compared across builds.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let sd1: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)(A;;FR;;;WD)".parse().unwrap();
+    /// let sd2: LocalBox<SecurityDescriptor> = "D:(A;;FR;;;WD)(A;;FA;;;SY)".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     sd1.dacl().unwrap().permission_set_hash(),
+    ///     sd2.dacl().unwrap().permission_set_hash(),
+    /// );
+    /// ```
+    pub fn permission_set_hash(&self) -> u64 {
+        let permissions: BTreeSet<_> = self
+            .aces()
+            .map(|ace| (ace.ace_type(), ace.mask().bits(), ace.sid()))
+            .collect();
+
+        let mut hasher = DefaultHasher::new();
+        permissions.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Copy this ACL into a new, independent [`OwnedAcl`]
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)".parse().unwrap();
+    /// let other: LocalBox<SecurityDescriptor> = "D:(A;;FR;;;WD)".parse().unwrap();
+    ///
+    /// let mut dacl = sd.dacl().unwrap().to_owned().unwrap();
+    /// dacl.add_ace(other.dacl().unwrap().get_ace(0).unwrap()).unwrap();
+    ///
+    /// let sd2 = sd.with_dacl(&dacl).unwrap();
+    /// assert_eq!(sd2.as_sddl().unwrap(), "D:(A;;FA;;;SY)(A;;FR;;;WD)");
+    /// ```
+    pub fn to_owned(&self) -> io::Result<OwnedAcl> {
+        OwnedAcl::copy_from_bytes(self.as_bytes())
+    }
+
+    /// Get a copy of this ACL in a deterministic order, without duplicates
+    ///
+    /// Explicit ACEs come first and inherited ACEs follow, as Windows expects.
+    /// Within each group, access-denied ACEs come before any others, and ACEs
+    /// are otherwise sorted by their bytes. ACEs that are byte-for-byte
+    /// identical to an earlier ACE are dropped. The copy has no free space.
+    pub(crate) fn normalized(&self) -> io::Result<OwnedAcl> {
+        let (mut explicit, mut inherited): (Vec<&Ace>, Vec<&Ace>) = self
+            .aces()
+            .partition(|ace| !ace.flags().contains(constants::AceFlags::Inherited));
+
+        fn order<'a>(ace: &&'a Ace) -> (bool, &'a [u8]) {
+            let denied = matches!(
+                ace.ace_type(),
+                constants::AceType::ACCESS_DENIED_ACE_TYPE
+                    | constants::AceType::ACCESS_DENIED_CALLBACK_ACE_TYPE
+                    | constants::AceType::ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE
+                    | constants::AceType::ACCESS_DENIED_OBJECT_ACE_TYPE
+            );
+            (!denied, ace.as_bytes())
+        }
+
+        explicit.sort_by_key(order);
+        inherited.sort_by_key(order);
+
+        let mut seen = HashSet::new();
+        let aces: Vec<&Ace> = explicit
+            .into_iter()
+            .chain(inherited)
+            .filter(|ace| seen.insert(ace.as_bytes()))
+            .collect();
+
+        let size =
+            mem::size_of::<ACL>() as u32 + aces.iter().map(|ace| ace.size() as u32).sum::<u32>();
+        let mut acl = wrappers::InitializeAcl(size, self.revision_level())?;
+
+        for ace in aces {
+            wrappers::AddAce(&mut acl, u32::MAX, ace)?;
+        }
+
+        Ok(OwnedAcl::from_inner(acl))
+    }
+
+    /// Find the SIDs in this ACL that do not map to any account
+    ///
+    /// Each ACE's SID is looked up with [`wrappers::LookupAccountSid`]. SIDs
+    /// for which the lookup fails with `ERROR_NONE_MAPPED` (typically
+    /// deleted accounts) are returned in ACE order. Any other lookup error is
+    /// returned as-is.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor, Sid};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> =
+    ///     "D:(A;;FA;;;S-1-5-21-99999-99999-99999-99999)(A;;FR;;;WD)"
+    ///         .parse()
+    ///         .unwrap();
+    /// let bogus: LocalBox<Sid> = "S-1-5-21-99999-99999-99999-99999".parse().unwrap();
+    ///
+    /// assert_eq!(sd.dacl().unwrap().unresolved_sids().unwrap(), vec![bogus]);
+    /// ```
+    pub fn unresolved_sids(&self) -> io::Result<Vec<LocalBox<Sid>>> {
+        let mut unresolved = Vec::new();
+
+        for ace in self {
+            let sid = match ace.sid() {
+                Some(sid) => sid,
+                None => continue,
+            };
+
+            match wrappers::LookupAccountSid(sid) {
+                Ok(_) => (),
+                Err(ref e) if e.raw_os_error() == Some(ERROR_NONE_MAPPED as i32) => {
+                    unresolved.push(wrappers::CopySid(sid)?)
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(unresolved)
+    }
+}
+
+/// The size of an [`Acl`] and the number of ACEs it holds.
+///
+/// Returned by [`wrappers::GetAclInformationSize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AclSizeInformation {
+    /// The number of ACEs in the ACL
+    pub ace_count: u32,
+
+    /// The number of bytes used by the ACL header and its ACEs
+    pub bytes_in_use: u32,
+
+    /// The number of unused bytes at the end of the ACL
+    pub bytes_free: u32,
+}
+
+/// An iterator over the ACEs in an [`Acl`].
+///
+/// Created by [`Acl::aces`].
+#[derive(Debug, Clone)]
+pub struct AceIter<'a> {
+    acl: &'a Acl,
+    next: u32,
+    len: u32,
+}
+
+impl<'a> Iterator for AceIter<'a> {
+    type Item = &'a Ace;
+
+    fn next(&mut self) -> Option<&'a Ace> {
+        if self.next >= self.len {
+            return None;
+        }
+
+        let ace = self.acl.get_ace(self.next);
+        self.next += 1;
+        ace
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.next) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for AceIter<'_> {}
+
+impl<'a> IntoIterator for &'a Acl {
+    type Item = &'a Ace;
+    type IntoIter = AceIter<'a>;
+
+    fn into_iter(self) -> AceIter<'a> {
+        self.aces()
+    }
 }
 
 impl fmt::Debug for Acl {
@@ -125,6 +419,62 @@ mod test {
     use crate::constants::AceType;
     use crate::{LocalBox, SecurityDescriptor};
 
+    #[test]
+    fn permission_set_hash() -> io::Result<()> {
+        let byte_hash = |acl: &Acl| {
+            let mut hasher = DefaultHasher::new();
+            acl.as_bytes().hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let sd1: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)(D;;FW;;;BG)(A;;FR;;;WD)".parse()?;
+        let sd2: LocalBox<SecurityDescriptor> = "D:(A;;FR;;;WD)(A;;FA;;;SY)(D;;FW;;;BG)".parse()?;
+        let acl1 = sd1.dacl().unwrap();
+        let acl2 = sd2.dacl().unwrap();
+
+        assert_eq!(acl1.permission_set_hash(), acl2.permission_set_hash());
+        assert_ne!(byte_hash(acl1), byte_hash(acl2));
+
+        // A different mask, type, or SID changes the set
+        for other in [
+            "D:(A;;FA;;;SY)(D;;FW;;;BG)(A;;FX;;;WD)",
+            "D:(A;;FA;;;SY)(A;;FW;;;BG)(A;;FR;;;WD)",
+            "D:(A;;FA;;;SY)(D;;FW;;;BU)(A;;FR;;;WD)",
+            "D:(A;;FA;;;SY)(A;;FR;;;WD)",
+        ] {
+            let sd: LocalBox<SecurityDescriptor> = other.parse()?;
+            assert_ne!(
+                sd.dacl().unwrap().permission_set_hash(),
+                acl1.permission_set_hash(),
+                "{}",
+                other
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn effective_rights_trustee_forms() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)(A;;FR;;;WD)".parse()?;
+        let acl = sd.dacl().unwrap();
+
+        let system: LocalBox<Sid> = "SY".parse()?;
+        let (name, domain) = system.lookup_account()?;
+        let name = format!("{}\\{}", domain.to_string_lossy(), name.to_string_lossy());
+        let trustee = Trustee::from(&*system);
+
+        let by_sid = acl.effective_rights(&*system)?;
+        let by_name = acl.effective_rights(name.as_str())?;
+        let by_trustee = acl.effective_rights(&trustee)?;
+
+        assert_eq!(by_sid, constants::AccessRights::FileAllAccess);
+        assert_eq!(by_name, by_sid);
+        assert_eq!(by_trustee, by_sid);
+
+        Ok(())
+    }
+
     #[test]
     fn get_len() -> io::Result<()> {
         let limit = 100;
@@ -172,4 +522,102 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn iterate_aces() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> =
+            "D:(A;;FA;;;SY)(D;;FW;;;BG)(A;;FR;;;WD)(A;;FR;;;BU)".parse()?;
+        let acl = sd.dacl().unwrap();
+
+        let mut seen = 0;
+        for (index, ace) in acl.into_iter().enumerate() {
+            assert_eq!(ace.sid(), acl.get_ace(index as u32).unwrap().sid());
+            seen += 1;
+        }
+        assert_eq!(seen, acl.len());
+
+        let mut looped = 0;
+        for _ in acl {
+            looped += 1;
+        }
+        assert_eq!(looped, 4);
+
+        assert_eq!(acl.aces().len(), 4);
+        assert_eq!(acl.aces().filter(|_| true).count(), 4);
+        assert_eq!(
+            acl.aces()
+                .filter(|ace| ace.ace_type() == AceType::ACCESS_ALLOWED_ACE_TYPE)
+                .count(),
+            3
+        );
+
+        let empty: LocalBox<SecurityDescriptor> = "D:".parse()?;
+        assert_eq!(empty.dacl().unwrap().aces().next().map(|_| ()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_counts() -> io::Result<()> {
+        use crate::constants::AceType::*;
+
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)(D;;FW;;;BG)(A;;FR;;;WD)(A;;FR;;;BU)\
+             S:(AU;SA;FA;;;WD)(AU;FA;FW;;;BU)(ML;;NW;;;HI)"
+            .parse()?;
+
+        let dacl_counts = sd.dacl().unwrap().type_counts();
+        assert_eq!(dacl_counts.len(), 2);
+        assert_eq!(dacl_counts[&ACCESS_ALLOWED_ACE_TYPE], 3);
+        assert_eq!(dacl_counts[&ACCESS_DENIED_ACE_TYPE], 1);
+
+        let sacl_counts = sd.sacl().unwrap().type_counts();
+        assert_eq!(sacl_counts.len(), 2);
+        assert_eq!(sacl_counts[&SYSTEM_AUDIT_ACE_TYPE], 2);
+        assert_eq!(sacl_counts[&SYSTEM_MANDATORY_LABEL_ACE_TYPE], 1);
+
+        let empty: LocalBox<SecurityDescriptor> = "D:".parse()?;
+        assert!(empty.dacl().unwrap().type_counts().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unresolved_sids() -> io::Result<()> {
+        let bogus: LocalBox<Sid> = "S-1-5-21-99999-99999-99999-99999".parse()?;
+        let sd: LocalBox<SecurityDescriptor> =
+            format!("D:(A;;FR;;;WD)(A;;FA;;;{})(A;;FA;;;SY)", bogus).parse()?;
+
+        let unresolved = sd.dacl().unwrap().unresolved_sids()?;
+
+        assert_eq!(unresolved, vec![bogus]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_owned_is_independent() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)(A;;FR;;;WD)".parse()?;
+        let extra: LocalBox<SecurityDescriptor> = "D:(D;;FW;;;BG)".parse()?;
+        let dacl = sd.dacl().unwrap();
+
+        let mut owned = dacl.to_owned()?;
+        assert_eq!(owned.as_bytes(), dacl.as_bytes());
+
+        owned.add_ace(extra.dacl().unwrap().get_ace(0).unwrap())?;
+
+        assert_eq!(owned.len(), 3);
+        assert_eq!(
+            owned.get_ace(2).unwrap().sid(),
+            extra.dacl().unwrap().get_ace(0).unwrap().sid()
+        );
+
+        // The original descriptor is untouched
+        assert_eq!(sd.dacl().unwrap().len(), 2);
+        assert_eq!(
+            sd.as_sddl()?,
+            std::ffi::OsStr::new("D:(A;;FA;;;SY)(A;;FR;;;WD)")
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,464 @@
+use crate::constants::{AccessRights, AceFlags, AceType};
+use crate::ffi::shared::minwindef::DWORD;
+use crate::ffi::um::winnt::{ACCESS_ALLOWED_ACE, ACL};
+use crate::structures::owned_acl::align_size;
+use crate::{wrappers, Acl, OwnedAcl, Sid};
+use std::io;
+use std::mem;
+
+/// A single entry to be added by an [`AclBuilder`].
+///
+/// Only access-allowed, access-denied, and system audit entries can be
+/// created, so every `AceEntry` can be added to an ACL.
+///
+/// ```
+/// use windows_permissions::{LocalBox, Sid};
+/// use windows_permissions::constants::{AccessRights, AceFlags, AceType};
+/// use windows_permissions::structures::AceEntry;
+///
+/// let system: LocalBox<Sid> = "SY".parse().unwrap();
+///
+/// let entry = AceEntry::allow(&system, AccessRights::FileAllAccess, AceFlags::empty());
+/// assert_eq!(entry.ace_type(), AceType::ACCESS_ALLOWED_ACE_TYPE);
+///
+/// let label = AceEntry::new(
+///     AceType::SYSTEM_MANDATORY_LABEL_ACE_TYPE,
+///     AceFlags::empty(),
+///     AccessRights::MandatoryLabelNoWriteUp,
+///     &system,
+/// );
+/// assert!(label.is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AceEntry<'s> {
+    kind: EntryKind,
+    flags: AceFlags,
+    mask: AccessRights,
+    sid: &'s Sid,
+}
+
+/// The ACE types an [`AceEntry`] can hold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntryKind {
+    Allow,
+    Deny,
+    Audit,
+}
+
+impl<'s> AceEntry<'s> {
+    /// Create an entry of the given ACE type
+    ///
+    /// Fails with `InvalidInput` unless `ace_type` is
+    /// `ACCESS_ALLOWED_ACE_TYPE`, `ACCESS_DENIED_ACE_TYPE`, or
+    /// `SYSTEM_AUDIT_ACE_TYPE`.
+    pub fn new(
+        ace_type: AceType,
+        flags: AceFlags,
+        mask: AccessRights,
+        sid: &'s Sid,
+    ) -> io::Result<Self> {
+        let kind = match ace_type {
+            AceType::ACCESS_ALLOWED_ACE_TYPE => EntryKind::Allow,
+            AceType::ACCESS_DENIED_ACE_TYPE => EntryKind::Deny,
+            AceType::SYSTEM_AUDIT_ACE_TYPE => EntryKind::Audit,
+            other => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("AceEntry does not support {:?}", other),
+                ))
+            }
+        };
+
+        Ok(Self {
+            kind,
+            flags,
+            mask,
+            sid,
+        })
+    }
+
+    /// Create an access-allowed entry
+    pub fn allow(sid: &'s Sid, mask: AccessRights, flags: AceFlags) -> Self {
+        Self {
+            kind: EntryKind::Allow,
+            flags,
+            mask,
+            sid,
+        }
+    }
+
+    /// Create an access-denied entry
+    pub fn deny(sid: &'s Sid, mask: AccessRights, flags: AceFlags) -> Self {
+        Self {
+            kind: EntryKind::Deny,
+            flags,
+            mask,
+            sid,
+        }
+    }
+
+    /// Create a system audit entry
+    ///
+    /// Include `SuccessfulAccess` and/or `FailedAccess` in `flags` to choose
+    /// which accesses generate audit events.
+    pub fn audit(sid: &'s Sid, mask: AccessRights, flags: AceFlags) -> Self {
+        Self {
+            kind: EntryKind::Audit,
+            flags,
+            mask,
+            sid,
+        }
+    }
+
+    /// Get the kind of ACE this entry produces
+    pub fn ace_type(&self) -> AceType {
+        match self.kind {
+            EntryKind::Allow => AceType::ACCESS_ALLOWED_ACE_TYPE,
+            EntryKind::Deny => AceType::ACCESS_DENIED_ACE_TYPE,
+            EntryKind::Audit => AceType::SYSTEM_AUDIT_ACE_TYPE,
+        }
+    }
+
+    /// Get the ACE flags. For audit entries, `SuccessfulAccess` and
+    /// `FailedAccess` select which accesses are audited.
+    pub fn flags(&self) -> AceFlags {
+        self.flags
+    }
+
+    /// Get the access rights allowed, denied, or audited
+    pub fn mask(&self) -> AccessRights {
+        self.mask
+    }
+
+    /// Get the SID the entry applies to
+    pub fn sid(&self) -> &'s Sid {
+        self.sid
+    }
+
+    /// Get the size in bytes of the ACE this entry produces
+    ///
+    /// Allowed, denied, and audit ACEs all share the same layout: a header,
+    /// an access mask, and the SID.
+    pub fn size(&self) -> u32 {
+        let sid_len = wrappers::GetSidLengthRequired(self.sid.sub_authority_count());
+        (mem::size_of::<ACCESS_ALLOWED_ACE>() - mem::size_of::<DWORD>() + sid_len) as u32
+    }
+}
+
+/// A builder for [`OwnedAcl`]s.
+///
+/// Entries are added in order. [`AclBuilder::build`] works out the exact
+/// size of the finished ACL first, so it is allocated only once no matter how
+/// many entries there are.
+///
+/// ```
+/// use windows_permissions::{AclBuilder, LocalBox, SecurityDescriptor, Sid};
+/// use windows_permissions::constants::{AccessRights, AceFlags};
+///
+/// let system: LocalBox<Sid> = "SY".parse().unwrap();
+/// let guests: LocalBox<Sid> = "BG".parse().unwrap();
+///
+/// let acl = AclBuilder::new()
+///     .deny(&guests, AccessRights::FileGenericWrite, AceFlags::empty())
+///     .allow(&system, AccessRights::FileAllAccess, AceFlags::ContainerInherit)
+///     .build()
+///     .unwrap();
+///
+/// let sd: LocalBox<SecurityDescriptor> = "D:".parse().unwrap();
+/// assert_eq!(
+///     sd.with_dacl(&acl).unwrap().as_sddl().unwrap(),
+///     "D:(D;;FW;;;BG)(A;CI;FA;;;SY)"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AclBuilder<'s> {
+    entries: Vec<AceEntry<'s>>,
+    target: Option<AclTarget>,
+}
+
+/// Which kind of ACL a builder is restricted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AclTarget {
+    Dacl,
+    Sacl,
+}
+
+impl<'s> AclBuilder<'s> {
+    /// Create a builder with no entries
+    ///
+    /// The builder accepts any supported entry. Use [`AclBuilder::dacl`] or
+    /// [`AclBuilder::sacl`] to also check that the entries belong in that
+    /// kind of ACL.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a builder for a DACL
+    ///
+    /// [`build`](Self::build) fails if any entry is not valid in a DACL (see
+    /// [`AceType::valid_in_dacl`]).
+    ///
+    /// ```
+    /// use windows_permissions::{AclBuilder, LocalBox, Sid};
+    /// use windows_permissions::constants::{AccessRights, AceFlags};
+    ///
+    /// let world: LocalBox<Sid> = "WD".parse().unwrap();
+    ///
+    /// let err = AclBuilder::dacl()
+    ///     .audit(&world, AccessRights::FileAllAccess, AceFlags::FailedAccess)
+    ///     .build()
+    ///     .unwrap_err();
+    ///
+    /// assert!(err.to_string().contains("SYSTEM_AUDIT_ACE_TYPE"));
+    /// ```
+    pub fn dacl() -> Self {
+        Self {
+            target: Some(AclTarget::Dacl),
+            ..Self::default()
+        }
+    }
+
+    /// Create a builder for a SACL
+    ///
+    /// [`build`](Self::build) fails if any entry is not valid in a SACL (see
+    /// [`AceType::valid_in_sacl`]).
+    pub fn sacl() -> Self {
+        Self {
+            target: Some(AclTarget::Sacl),
+            ..Self::default()
+        }
+    }
+
+    /// Add an arbitrary entry
+    pub fn entry(mut self, entry: AceEntry<'s>) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Add an access-allowed entry
+    pub fn allow(self, sid: &'s Sid, mask: AccessRights, flags: AceFlags) -> Self {
+        self.entry(AceEntry::allow(sid, mask, flags))
+    }
+
+    /// Add an access-denied entry
+    pub fn deny(self, sid: &'s Sid, mask: AccessRights, flags: AceFlags) -> Self {
+        self.entry(AceEntry::deny(sid, mask, flags))
+    }
+
+    /// Add a system audit entry
+    ///
+    /// Include `SuccessfulAccess` and/or `FailedAccess` in `flags` to choose
+    /// which accesses generate audit events.
+    pub fn audit(self, sid: &'s Sid, mask: AccessRights, flags: AceFlags) -> Self {
+        self.entry(AceEntry::audit(sid, mask, flags))
+    }
+
+    /// Get the exact size in bytes of the ACL that `build` will allocate
+    pub fn size(&self) -> u32 {
+        let aces: u32 = self.entries.iter().map(AceEntry::size).sum();
+        align_size(mem::size_of::<ACL>() as u32 + aces)
+    }
+
+    /// Build the ACL with a single allocation
+    ///
+    /// Fails with `InvalidInput` if the builder is for a DACL or SACL and an
+    /// entry does not belong in it.
+    pub fn build(&self) -> io::Result<OwnedAcl> {
+        for (index, entry) in self.entries.iter().enumerate() {
+            let (valid, kind) = match self.target {
+                None => continue,
+                Some(AclTarget::Dacl) => (entry.ace_type().valid_in_dacl(), "DACL"),
+                Some(AclTarget::Sacl) => (entry.ace_type().valid_in_sacl(), "SACL"),
+            };
+
+            if !valid {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "Entry {} is a {:?}, which is not valid in a {}",
+                        index,
+                        entry.ace_type(),
+                        kind
+                    ),
+                ));
+            }
+        }
+
+        let mut acl = OwnedAcl::with_capacity(self.size())?;
+        acl.try_extend(self.entries.iter().copied())?;
+        Ok(acl)
+    }
+}
+
+impl AceEntry<'_> {
+    /// Append the ACE for this entry to `acl`
+    ///
+    /// `acl` must already have enough free space for the ACE.
+    pub(crate) fn add_to(&self, acl: &mut Acl) -> io::Result<()> {
+        match self.kind {
+            EntryKind::Allow => {
+                wrappers::AddAccessAllowedAceEx(acl, self.flags, self.mask, self.sid)
+            }
+            EntryKind::Deny => wrappers::AddAccessDeniedAceEx(acl, self.flags, self.mask, self.sid),
+            EntryKind::Audit => {
+                let audit_flags = AceFlags::SuccessfulAccess | AceFlags::FailedAccess;
+                wrappers::AddAuditAccessAceEx(
+                    acl,
+                    self.flags - audit_flags,
+                    self.mask,
+                    self.sid,
+                    self.flags.contains(AceFlags::SuccessfulAccess),
+                    self.flags.contains(AceFlags::FailedAccess),
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::LocalBox;
+
+    #[test]
+    fn large_acl_single_allocation() -> io::Result<()> {
+        let sids: Vec<LocalBox<Sid>> = (0..500)
+            .map(|rid| format!("S-1-5-21-1-2-3-{}", 1000 + rid).parse())
+            .collect::<io::Result<_>>()?;
+
+        let builder = sids.iter().fold(AclBuilder::new(), |builder, sid| {
+            builder.allow(sid, AccessRights::FileGenericRead, AceFlags::empty())
+        });
+
+        let expected_size = builder.size();
+        let acl = builder.build()?;
+
+        assert_eq!(acl.len(), 500);
+        assert_eq!(acl.capacity(), expected_size);
+
+        // The estimate was exact, so there is no unused space
+        let info = wrappers::GetAclInformationSize(&acl)?;
+        assert_eq!(info.bytes_free, 0);
+        assert_eq!(info.bytes_in_use, expected_size);
+
+        for (ace, sid) in acl.aces().zip(sids.iter()) {
+            assert_eq!(ace.sid(), Some(&**sid));
+            assert_eq!(
+                ace.size() as u32,
+                AceEntry::allow(sid, AccessRights::FileGenericRead, AceFlags::empty()).size()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn audit_flags() -> io::Result<()> {
+        let world: LocalBox<Sid> = "WD".parse()?;
+
+        let acl = AclBuilder::new()
+            .audit(
+                &world,
+                AccessRights::FileAllAccess,
+                AceFlags::FailedAccess | AceFlags::ObjectInherit,
+            )
+            .build()?;
+
+        let ace = acl.get_ace(0).unwrap();
+        assert_eq!(ace.ace_type(), AceType::SYSTEM_AUDIT_ACE_TYPE);
+        assert_eq!(
+            ace.flags(),
+            AceFlags::FailedAccess | AceFlags::ObjectInherit
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn entry_types() -> io::Result<()> {
+        let world: LocalBox<Sid> = "WD".parse()?;
+        let read = AccessRights::FileGenericRead;
+
+        for ace_type in [
+            AceType::ACCESS_ALLOWED_ACE_TYPE,
+            AceType::ACCESS_DENIED_ACE_TYPE,
+            AceType::SYSTEM_AUDIT_ACE_TYPE,
+        ] {
+            let entry = AceEntry::new(ace_type, AceFlags::empty(), read, &world)?;
+            assert_eq!(entry.ace_type(), ace_type);
+            assert_eq!(entry.mask(), read);
+            assert_eq!(entry.sid(), &*world);
+        }
+
+        assert_eq!(
+            AceEntry::allow(&world, read, AceFlags::empty()).ace_type(),
+            AceType::ACCESS_ALLOWED_ACE_TYPE
+        );
+        assert_eq!(
+            AceEntry::deny(&world, read, AceFlags::empty()).ace_type(),
+            AceType::ACCESS_DENIED_ACE_TYPE
+        );
+        assert_eq!(
+            AceEntry::audit(&world, read, AceFlags::FailedAccess).ace_type(),
+            AceType::SYSTEM_AUDIT_ACE_TYPE
+        );
+
+        let err = AceEntry::new(
+            AceType::SYSTEM_MANDATORY_LABEL_ACE_TYPE,
+            AceFlags::empty(),
+            AccessRights::empty(),
+            &world,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dacl_and_sacl_targets() -> io::Result<()> {
+        let world: LocalBox<Sid> = "WD".parse()?;
+        let read = AccessRights::FileGenericRead;
+
+        let dacl = AclBuilder::dacl()
+            .allow(&world, read, AceFlags::empty())
+            .deny(&world, read, AceFlags::empty())
+            .build()?;
+        assert_eq!(dacl.len(), 2);
+
+        let sacl = AclBuilder::sacl()
+            .audit(&world, read, AceFlags::SuccessfulAccess)
+            .build()?;
+        assert_eq!(sacl.len(), 1);
+
+        let err = AclBuilder::dacl()
+            .allow(&world, read, AceFlags::empty())
+            .audit(&world, read, AceFlags::SuccessfulAccess)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(err.to_string().contains("Entry 1"), "{}", err);
+        assert!(err.to_string().contains("DACL"), "{}", err);
+
+        let err = AclBuilder::sacl()
+            .allow(&world, read, AceFlags::empty())
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(
+            err.to_string().contains("ACCESS_ALLOWED_ACE_TYPE"),
+            "{}",
+            err
+        );
+        assert!(err.to_string().contains("SACL"), "{}", err);
+
+        // An unrestricted builder still accepts a mix
+        let mixed = AclBuilder::new()
+            .allow(&world, read, AceFlags::empty())
+            .audit(&world, read, AceFlags::SuccessfulAccess)
+            .build()?;
+        assert_eq!(mixed.len(), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use crate::constants::AccessRights;
+use crate::ffi::um::winnt::GENERIC_MAPPING;
+
+/// The mapping from generic access rights to object-specific rights.
+///
+/// See [MSDN](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-generic_mapping)
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GenericMapping {
+    /// Rights granted by `GenericRead`
+    pub read: AccessRights,
+
+    /// Rights granted by `GenericWrite`
+    pub write: AccessRights,
+
+    /// Rights granted by `GenericExecute`
+    pub execute: AccessRights,
+
+    /// Rights granted by `GenericAll`
+    pub all: AccessRights,
+}
+
+impl GenericMapping {
+    /// The generic mapping used for files
+    pub fn file() -> Self {
+        Self {
+            read: AccessRights::FileGenericRead,
+            write: AccessRights::FileGenericWrite,
+            execute: AccessRights::FileGenericExecute,
+            all: AccessRights::FileAllAccess,
+        }
+    }
+
+    /// Replace any generic rights in `rights` with their mapped rights
+    ///
+    /// This does the same thing as
+    /// [`MapGenericMask`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-mapgenericmask).
+    ///
+    /// ```
+    /// use windows_permissions::GenericMapping;
+    /// use windows_permissions::constants::AccessRights;
+    ///
+    /// let mapping = GenericMapping::file();
+    ///
+    /// assert_eq!(
+    ///     mapping.map(AccessRights::GenericRead | AccessRights::Delete),
+    ///     AccessRights::FileGenericRead | AccessRights::Delete
+    /// );
+    /// ```
+    pub fn map(&self, rights: AccessRights) -> AccessRights {
+        let generics = [
+            (AccessRights::GenericRead, self.read),
+            (AccessRights::GenericWrite, self.write),
+            (AccessRights::GenericExecute, self.execute),
+            (AccessRights::GenericAll, self.all),
+        ];
+
+        let mut mapped = rights;
+        for (generic, specific) in generics.iter() {
+            if rights.contains(*generic) {
+                mapped.remove(*generic);
+                mapped.insert(*specific);
+            }
+        }
+
+        mapped
+    }
+
+    /// Get the underlying Windows structure
+    pub(crate) fn to_raw(self) -> GENERIC_MAPPING {
+        GENERIC_MAPPING {
+            GenericRead: self.read.bits(),
+            GenericWrite: self.write.bits(),
+            GenericExecute: self.execute.bits(),
+            GenericAll: self.all.bits(),
+        }
+    }
+}
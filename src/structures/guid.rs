@@ -0,0 +1,171 @@
+use crate::ffi::{self, shared::guiddef::GUID};
+#[cfg(feature = "schema")]
+use crate::structures::WellKnownObjectType;
+use std::ffi::c_void;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::str::FromStr;
+
+/// A globally unique identifier.
+///
+/// GUIDs identify object types, property sets, and properties in
+/// object-specific ACEs and access checks.
+///
+/// See [MSDN](https://docs.microsoft.com/en-us/windows/win32/api/guiddef/ns-guiddef-guid)
+/// for details.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Guid {
+    inner: GUID,
+}
+
+impl Guid {
+    /// Create a GUID from its component fields
+    pub const fn new(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> Self {
+        Self {
+            inner: ffi::guid(data1, data2, data3, data4),
+        }
+    }
+
+    /// Get a pointer to the underlying `GUID` structure
+    pub fn as_ptr(&self) -> *const c_void {
+        &self.inner as *const GUID as *const _
+    }
+
+    /// Identify a common Active Directory schema object class or property set
+    ///
+    /// Returns `None` for GUIDs that are not in [`WellKnownObjectType`].
+    ///
+    /// ```
+    /// use windows_permissions::Guid;
+    /// use windows_permissions::structures::WellKnownObjectType;
+    ///
+    /// let user: Guid = "bf967aba-0de6-11d0-a285-00aa003049e2".parse().unwrap();
+    /// assert_eq!(user.as_well_known_object(), Some(WellKnownObjectType::User));
+    /// ```
+    #[cfg(feature = "schema")]
+    pub fn as_well_known_object(&self) -> Option<WellKnownObjectType> {
+        WellKnownObjectType::from_guid(self)
+    }
+
+    fn fields(&self) -> (u32, u16, u16, [u8; 8]) {
+        ffi::guid_fields(&self.inner)
+    }
+}
+
+impl fmt::Debug for Guid {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Guid({})", self)
+    }
+}
+
+impl fmt::Display for Guid {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (data1, data2, data3, data4) = self.fields();
+        write!(
+            fmt,
+            "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-",
+            data1, data2, data3, data4[0], data4[1]
+        )?;
+
+        for byte in &data4[2..] {
+            write!(fmt, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Guid {
+    type Err = io::Error;
+
+    /// Parse a GUID in `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` form, with or
+    /// without surrounding braces
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidInput, "Invalid GUID string");
+
+        let s = match (s.strip_prefix('{'), s.strip_suffix('}')) {
+            (Some(_), Some(_)) => &s[1..s.len() - 1],
+            (None, None) => s,
+            _ => return Err(invalid()),
+        };
+
+        let groups: Vec<&str> = s.split('-').collect();
+        let lengths = [8, 4, 4, 4, 12];
+
+        if groups.len() != lengths.len()
+            || groups.iter().zip(lengths.iter()).any(|(group, len)| {
+                group.len() != *len || !group.bytes().all(|b| b.is_ascii_hexdigit())
+            })
+        {
+            return Err(invalid());
+        }
+
+        let data1 = u32::from_str_radix(groups[0], 16).map_err(|_| invalid())?;
+        let data2 = u16::from_str_radix(groups[1], 16).map_err(|_| invalid())?;
+        let data3 = u16::from_str_radix(groups[2], 16).map_err(|_| invalid())?;
+
+        let tail = format!("{}{}", groups[3], groups[4]);
+        let mut data4 = [0u8; 8];
+        for (i, byte) in data4.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&tail[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+        }
+
+        Ok(Guid::new(data1, data2, data3, data4))
+    }
+}
+
+impl Eq for Guid {}
+impl PartialEq for Guid {
+    fn eq(&self, other: &Guid) -> bool {
+        self.fields() == other.fields()
+    }
+}
+
+impl Hash for Guid {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.fields().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_and_display() {
+        let text = "c434c045-9b91-4504-a2a0-aea9e781ec69";
+        let guid: Guid = text.parse().unwrap();
+
+        assert_eq!(
+            guid,
+            Guid::new(
+                0xc434c045,
+                0x9b91,
+                0x4504,
+                [0xa2, 0xa0, 0xae, 0xa9, 0xe7, 0x81, 0xec, 0x69]
+            )
+        );
+        assert_eq!(guid.to_string(), text);
+        assert_eq!(format!("{{{}}}", text).parse::<Guid>().unwrap(), guid);
+        assert_eq!(text.to_uppercase().parse::<Guid>().unwrap(), guid);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let invalid = [
+            "",
+            "c434c045-9b91-4504-a2a0-aea9e781ec6",
+            "c434c045-9b91-4504-a2a0-aea9e781ec699",
+            "c434c0459b91-4504-a2a0-aea9e781ec69",
+            "{c434c045-9b91-4504-a2a0-aea9e781ec69",
+            "c434c045-9b91-4504-a2a0-aea9e781ec6g",
+            "+434c045-9b91-4504-a2a0-aea9e781ec69",
+        ];
+
+        for text in invalid.iter() {
+            assert!(text.parse::<Guid>().is_err(), "{} parsed", text);
+        }
+    }
+}
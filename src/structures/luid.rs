@@ -0,0 +1,45 @@
+use crate::ffi::um::winnt::LUID;
+
+/// A locally unique identifier.
+///
+/// A LUID is unique on the system that created it until that system
+/// restarts. Privileges are identified by LUIDs.
+///
+/// See [MSDN](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-luid)
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Luid {
+    low_part: u32,
+    high_part: i32,
+}
+
+impl Luid {
+    /// Create a LUID from its low and high parts
+    pub const fn new(low_part: u32, high_part: i32) -> Self {
+        Self {
+            low_part,
+            high_part,
+        }
+    }
+
+    /// Get the low 32 bits of the LUID
+    pub fn low_part(&self) -> u32 {
+        self.low_part
+    }
+
+    /// Get the high 32 bits of the LUID
+    pub fn high_part(&self) -> i32 {
+        self.high_part
+    }
+
+    pub(crate) fn from_raw(raw: LUID) -> Self {
+        Self::new(raw.LowPart, raw.HighPart)
+    }
+
+    pub(crate) fn to_raw(self) -> LUID {
+        LUID {
+            LowPart: self.low_part,
+            HighPart: self.high_part,
+        }
+    }
+}
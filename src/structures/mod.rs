@@ -2,12 +2,28 @@
 
 mod ace;
 mod acl;
+mod acl_builder;
+mod generic_mapping;
+mod guid;
+mod luid;
+mod object_type_list_entry;
+mod owned_acl;
 mod sd;
 mod sid;
 mod trustee;
+#[cfg(feature = "schema")]
+mod well_known_object_type;
 
 pub use ace::Ace;
-pub use acl::Acl;
+pub use acl::{AceIter, Acl, AclSizeInformation};
+pub use acl_builder::{AceEntry, AclBuilder};
+pub use generic_mapping::GenericMapping;
+pub use guid::Guid;
+pub use luid::Luid;
+pub use object_type_list_entry::ObjectTypeListEntry;
+pub use owned_acl::OwnedAcl;
 pub use sd::SecurityDescriptor;
-pub use sid::Sid;
+pub use sid::{CapabilitySid, ResolvedAccount, Sid, WellKnownSids};
 pub use trustee::{Trustee, TrusteeSubject};
+#[cfg(feature = "schema")]
+pub use well_known_object_type::WellKnownObjectType;
@@ -0,0 +1,19 @@
+use crate::Guid;
+
+/// A node in an object type list, used for access checks against
+/// hierarchical object types.
+///
+/// The list is a flattened tree: the first entry has level 0 and identifies
+/// the object itself (`ACCESS_OBJECT_GUID`), and later entries describe
+/// property sets (level 1) and properties (level 2) beneath it.
+///
+/// See [MSDN](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-object_type_list)
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObjectTypeListEntry {
+    /// The level of the node in the object type tree
+    pub level: u16,
+
+    /// The GUID identifying the object type, property set, or property
+    pub object_type: Guid,
+}
@@ -0,0 +1,886 @@
+use crate::constants::{AceFlags, AclRevision, IntegrityLevel, MandatoryPolicy};
+use crate::ffi::um::winnt::{
+    ACE_HEADER, ACL, SECURITY_MANDATORY_LABEL_AUTHORITY, SYSTEM_MANDATORY_LABEL_ACE,
+};
+use crate::structures::AceEntry;
+use crate::{wrappers, Ace, Acl, LocalBox, Sid};
+use std::fmt;
+use std::io;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+/// An access control list that owns its memory and can grow.
+///
+/// An `OwnedAcl` dereferences to [`Acl`], so every read-only `Acl`
+/// operation is available on it. Use [`Acl::to_owned`] to get an
+/// independent copy of a borrowed ACL.
+pub struct OwnedAcl {
+    inner: LocalBox<Acl>,
+}
+
+impl OwnedAcl {
+    /// Create a new, empty ACL
+    ///
+    /// ```
+    /// use windows_permissions::OwnedAcl;
+    ///
+    /// let acl = OwnedAcl::new().unwrap();
+    ///
+    /// assert_eq!(acl.len(), 0);
+    /// ```
+    pub fn new() -> io::Result<Self> {
+        Ok(Self {
+            inner: wrappers::InitializeAcl(
+                mem::size_of::<ACL>() as u32,
+                AclRevision::ACL_REVISION,
+            )?,
+        })
+    }
+
+    /// Create a new, empty ACL with room for `bytes` bytes in total
+    ///
+    /// `bytes` includes the 8-byte ACL header, and is rounded up to a multiple
+    /// of 4. ACEs can be added without reallocating until the space runs out.
+    ///
+    /// ```
+    /// use windows_permissions::OwnedAcl;
+    ///
+    /// let acl = OwnedAcl::with_capacity(1024).unwrap();
+    ///
+    /// assert_eq!(acl.len(), 0);
+    /// assert_eq!(acl.capacity(), 1024);
+    /// ```
+    pub fn with_capacity(bytes: u32) -> io::Result<Self> {
+        let bytes = align_size(bytes.max(mem::size_of::<ACL>() as u32));
+
+        Ok(Self {
+            inner: wrappers::InitializeAcl(bytes, AclRevision::ACL_REVISION)?,
+        })
+    }
+
+    /// Take ownership of an ACL allocated with [`wrappers::InitializeAcl`]
+    pub(crate) fn from_inner(inner: LocalBox<Acl>) -> Self {
+        Self { inner }
+    }
+
+    /// Get the total size of the ACL buffer in bytes, including free space
+    pub fn capacity(&self) -> u32 {
+        self.as_bytes().len() as u32
+    }
+
+    /// Make sure there are at least `additional_bytes` bytes of free space
+    ///
+    /// If there is not enough free space, the ACEs are moved into a larger
+    /// buffer. The ACEs themselves are unchanged, and the extra space is
+    /// unused capacity that later calls to [`add_ace`](Self::add_ace) fill
+    /// without reallocating.
+    ///
+    /// ACLs cannot be larger than 64 KiB; reserving beyond that fails.
+    ///
+    /// ```
+    /// use windows_permissions::OwnedAcl;
+    ///
+    /// let mut acl = OwnedAcl::new().unwrap();
+    /// acl.reserve(100).unwrap();
+    ///
+    /// assert_eq!(acl.len(), 0);
+    /// assert!(acl.capacity() >= 108);
+    /// ```
+    pub fn reserve(&mut self, additional_bytes: u32) -> io::Result<()> {
+        let info = wrappers::GetAclInformationSize(&self.inner)?;
+
+        if info.bytes_free >= additional_bytes {
+            return Ok(());
+        }
+
+        self.grow(required_size(info.bytes_in_use, additional_bytes)?)
+    }
+
+    /// Copy the raw bytes of an ACL into a new `OwnedAcl`
+    ///
+    /// The bytes may come from an untrusted source. The header and the
+    /// bounds of every ACE are checked before anything is copied, and the
+    /// copy is validated by Windows afterwards. Any bytes past the ACL's
+    /// `AclSize` are ignored.
+    pub(crate) fn copy_from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let size = check_bounds(bytes)?;
+
+        let inner = unsafe {
+            let inner: LocalBox<Acl> = LocalBox::try_allocate(false, size)?;
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), inner.as_ptr() as *mut u8, size);
+            inner
+        };
+
+        if !wrappers::IsValidAcl(&inner) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Copied ACL failed validation",
+            ));
+        }
+
+        Ok(Self { inner })
+    }
+
+    /// Append an ACE to the end of the ACL
+    ///
+    /// If there is not enough free space for the ACE, the ACL is reallocated
+    /// with at least double its capacity, up to the 64 KiB limit, so adding
+    /// many ACEs one at a time only reallocates a few times.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, OwnedAcl, SecurityDescriptor};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)(A;;FR;;;WD)".parse().unwrap();
+    /// let source = sd.dacl().unwrap();
+    ///
+    /// let mut acl = OwnedAcl::new().unwrap();
+    /// acl.add_ace(source.get_ace(1).unwrap()).unwrap();
+    /// acl.add_ace(source.get_ace(0).unwrap()).unwrap();
+    ///
+    /// assert_eq!(acl.len(), 2);
+    /// assert_eq!(acl.get_ace(0).unwrap().sid(), source.get_ace(1).unwrap().sid());
+    /// ```
+    pub fn add_ace(&mut self, ace: &Ace) -> io::Result<()> {
+        self.make_room(ace.size() as u32)?;
+        wrappers::AddAce(&mut self.inner, u32::MAX, ace)
+    }
+
+    /// Insert an entry so that it becomes the ACE at `index`
+    ///
+    /// The ACEs from `index` onwards move down by one. `index` may be equal
+    /// to [`len`](Acl::len), which appends the entry; anything larger fails
+    /// with `InvalidInput`.
+    ///
+    /// The ACL is left in exactly the order given, so this is for callers
+    /// that manage ACE order themselves. Like [`add_ace`](Self::add_ace),
+    /// this at least doubles the capacity when the ACL has to grow.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, OwnedAcl, SecurityDescriptor, Sid};
+    /// use windows_permissions::constants::{AccessRights, AceFlags};
+    /// use windows_permissions::structures::AceEntry;
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)".parse().unwrap();
+    /// let mut acl = sd.dacl().unwrap().to_owned().unwrap();
+    ///
+    /// let guests: LocalBox<Sid> = "BG".parse().unwrap();
+    /// let entry = AceEntry::deny(&guests, AccessRights::FileAllAccess, AceFlags::empty());
+    ///
+    /// acl.insert_ace(0, &entry).unwrap();
+    ///
+    /// assert_eq!(acl.get_ace(0).unwrap().sid(), Some(&*guests));
+    /// ```
+    pub fn insert_ace(&mut self, index: u32, entry: &AceEntry) -> io::Result<()> {
+        if index > self.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Cannot insert at index {} in an ACL with {} ACEs",
+                    index,
+                    self.len()
+                ),
+            ));
+        }
+
+        // Build the ACE on its own, then copy it into place
+        let mut single = OwnedAcl::with_capacity(mem::size_of::<ACL>() as u32 + entry.size())?;
+        entry.add_to(&mut single.inner)?;
+        let ace = single
+            .get_ace(0)
+            .expect("Valid ACL had no ACE after adding one");
+
+        self.make_room(ace.size() as u32)?;
+        wrappers::AddAce(&mut self.inner, index, ace)
+    }
+
+    /// Append a mandatory label ACE, which sets an object's integrity level
+    ///
+    /// The label SID is `S-1-16-x`, where `x` is the RID of `level`, and
+    /// `policy` says what subjects with a lower integrity level may not do.
+    /// Mandatory labels belong in a SACL, and Windows only honours the first
+    /// one. The ACE has no flags; use
+    /// [`set_all_inherit`](Self::set_all_inherit) to make it inheritable.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, OwnedAcl, SecurityDescriptor};
+    /// use windows_permissions::constants::{IntegrityLevel, MandatoryPolicy};
+    ///
+    /// let mut sacl = OwnedAcl::new().unwrap();
+    /// sacl.add_mandatory_label(
+    ///     IntegrityLevel::SECURITY_MANDATORY_LOW_RID,
+    ///     MandatoryPolicy::NoWriteUp,
+    /// ).unwrap();
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "O:SY".parse().unwrap();
+    /// let sd = sd.with_sacl(&sacl).unwrap();
+    ///
+    /// assert_eq!(sd.as_sddl().unwrap(), "O:SYS:(ML;;NW;;;LW)");
+    /// ```
+    pub fn add_mandatory_label(
+        &mut self,
+        level: IntegrityLevel,
+        policy: MandatoryPolicy,
+    ) -> io::Result<()> {
+        let label = Sid::new(SECURITY_MANDATORY_LABEL_AUTHORITY, &[level as u32])?;
+        let size = mem::size_of::<SYSTEM_MANDATORY_LABEL_ACE>() - mem::size_of::<u32>()
+            + wrappers::GetSidLengthRequired(label.sub_authority_count());
+
+        self.reserve(size as u32)?;
+        wrappers::AddMandatoryAce(&mut self.inner, AceFlags::empty(), policy, &label)
+    }
+
+    /// Set or clear the inheritance flags on every ACE
+    ///
+    /// `container` controls [`AceFlags::ContainerInherit`] and `object`
+    /// controls [`AceFlags::ObjectInherit`]. The flags byte of each ACE is
+    /// rewritten in place, and all other flags are left as they were.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    /// use windows_permissions::constants::AceFlags;
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)(A;;FR;;;WD)".parse().unwrap();
+    /// let mut acl = sd.dacl().unwrap().to_owned().unwrap();
+    ///
+    /// acl.set_all_inherit(true, false).unwrap();
+    ///
+    /// assert!(acl.aces().all(|ace| ace.flags() == AceFlags::ContainerInherit));
+    /// ```
+    pub fn set_all_inherit(&mut self, container: bool, object: bool) -> io::Result<()> {
+        let inherit = AceFlags::ContainerInherit | AceFlags::ObjectInherit;
+        let mut wanted = AceFlags::empty();
+        wanted.set(AceFlags::ContainerInherit, container);
+        wanted.set(AceFlags::ObjectInherit, object);
+
+        let base = self.inner.as_ptr() as *mut u8;
+
+        for index in 0..self.len() {
+            let ace = wrappers::GetAce(&self.inner, index)?;
+            let offset = ace as *const Ace as usize - base as usize;
+
+            // Byte 1 of the ACE header is the flags byte. Bits without an
+            // `AceFlags` name are kept by working on the raw byte.
+            unsafe {
+                let flags = base.add(offset + 1);
+                *flags = (*flags & !inherit.bits()) | wanted.bits();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Keep only the ACEs for which `f` returns `true`
+    ///
+    /// `f` is called once for each ACE, in order, before anything is removed.
+    /// The ACEs that are kept stay in the same order, and the space freed by
+    /// the others stays in the ACL as free space.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    /// use windows_permissions::constants::AceType;
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "D:(D;;FW;;;BG)(A;;FA;;;SY)".parse().unwrap();
+    /// let mut acl = sd.dacl().unwrap().to_owned().unwrap();
+    ///
+    /// acl.retain(|ace| ace.ace_type() != AceType::ACCESS_DENIED_ACE_TYPE).unwrap();
+    ///
+    /// assert_eq!(acl.len(), 1);
+    /// ```
+    pub fn retain<F: FnMut(&Ace) -> bool>(&mut self, mut f: F) -> io::Result<()> {
+        let keep: Vec<bool> = self.aces().map(&mut f).collect();
+
+        // Removing from the back keeps the remaining indices valid
+        for (index, keep) in keep.into_iter().enumerate().rev() {
+            if !keep {
+                wrappers::DeleteAce(&mut self.inner, index as u32)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Append a batch of entries to the end of the ACL
+    ///
+    /// Space for the whole batch is reserved first, so the ACL is reallocated
+    /// at most once. If the space cannot be reserved, either because
+    /// allocation fails or because the ACL would be larger than 64 KiB,
+    /// nothing is added.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, OwnedAcl, Sid};
+    /// use windows_permissions::constants::{AccessRights, AceFlags};
+    /// use windows_permissions::structures::AceEntry;
+    ///
+    /// let system: LocalBox<Sid> = "SY".parse().unwrap();
+    /// let entry = AceEntry::allow(&system, AccessRights::FileAllAccess, AceFlags::empty());
+    ///
+    /// let mut acl = OwnedAcl::new().unwrap();
+    /// acl.try_extend(vec![entry; 3]).unwrap();
+    ///
+    /// assert_eq!(acl.len(), 3);
+    /// ```
+    pub fn try_extend<'s, I>(&mut self, entries: I) -> io::Result<()>
+    where
+        I: IntoIterator<Item = AceEntry<'s>>,
+    {
+        let entries: Vec<AceEntry<'s>> = entries.into_iter().collect();
+
+        self.reserve(entries.iter().map(AceEntry::size).sum())?;
+
+        for entry in &entries {
+            entry.add_to(&mut self.inner)?;
+        }
+
+        Ok(())
+    }
+
+    /// Make sure there are at least `additional_bytes` bytes of free space,
+    /// growing geometrically
+    ///
+    /// Unlike [`reserve`](Self::reserve), which grows to exactly the size
+    /// asked for, this at least doubles the capacity (capped at the largest
+    /// possible ACL), so repeated single-ACE additions are amortized.
+    fn make_room(&mut self, additional_bytes: u32) -> io::Result<()> {
+        let info = wrappers::GetAclInformationSize(&self.inner)?;
+
+        if info.bytes_free >= additional_bytes {
+            return Ok(());
+        }
+
+        let required = required_size(info.bytes_in_use, additional_bytes)?;
+        let doubled = self.capacity().saturating_mul(2).min(MAX_ACL_SIZE);
+
+        self.grow(required.max(doubled))
+    }
+
+    /// Move the ACEs into a new ACL with room for `size` bytes in total
+    fn grow(&mut self, size: u32) -> io::Result<()> {
+        let mut grown = wrappers::InitializeAcl(align_size(size), self.revision_level())?;
+
+        for index in 0..self.len() {
+            let ace = self
+                .get_ace(index)
+                .expect("Valid ACL had fewer ACEs than it reported");
+            wrappers::AddAce(&mut grown, u32::MAX, ace)?;
+        }
+
+        self.inner = grown;
+        Ok(())
+    }
+}
+
+/// Check that the ACL header and every ACE it claims to have fit in `bytes`
+///
+/// Windows trusts the ACE count and sizes in an ACL, so an ACL that claims
+/// more ACEs than it holds would make `GetAce` read past the end of the
+/// buffer. This walks the ACEs without calling into Windows.
+///
+/// Returns the size of the ACL from its header.
+fn check_bounds(bytes: &[u8]) -> io::Result<usize> {
+    let invalid = |message| Err(io::Error::new(io::ErrorKind::InvalidData, message));
+
+    let header_size = mem::size_of::<ACL>();
+    let ace_header_size = mem::size_of::<ACE_HEADER>();
+
+    if bytes.len() < header_size {
+        return invalid("ACL bytes are shorter than an ACL header");
+    }
+
+    let acl_size = u16::from_le_bytes([bytes[2], bytes[3]]) as usize;
+    let ace_count = u16::from_le_bytes([bytes[4], bytes[5]]);
+
+    if acl_size < header_size || acl_size > bytes.len() {
+        return invalid("ACL size does not match the ACL bytes");
+    }
+
+    let mut offset = header_size;
+
+    for _ in 0..ace_count {
+        if offset + ace_header_size > acl_size {
+            return invalid("ACL claims more ACEs than it contains");
+        }
+
+        let ace_size = u16::from_le_bytes([bytes[offset + 2], bytes[offset + 3]]) as usize;
+
+        if ace_size < ace_header_size || offset + ace_size > acl_size {
+            return invalid("ACE extends past the end of the ACL");
+        }
+
+        offset += ace_size;
+    }
+
+    Ok(acl_size)
+}
+
+/// The largest ACL size that fits in the header's `u16` and is DWORD-aligned
+const MAX_ACL_SIZE: u32 = u16::MAX as u32 & !3;
+
+/// Get the aligned size of an ACL using `in_use` bytes plus `additional` more
+///
+/// Fails with `InvalidInput` if that would be larger than an ACL can be.
+fn required_size(in_use: u32, additional: u32) -> io::Result<u32> {
+    in_use
+        .checked_add(additional)
+        .filter(|&size| size <= MAX_ACL_SIZE)
+        .map(align_size)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "ACLs cannot be larger than 64 KiB",
+            )
+        })
+}
+
+/// Round an ACL size up to the DWORD alignment Windows requires
+pub(crate) fn align_size(size: u32) -> u32 {
+    (size + 3) & !3
+}
+
+impl Deref for OwnedAcl {
+    type Target = Acl;
+
+    fn deref(&self) -> &Acl {
+        &self.inner
+    }
+}
+
+impl DerefMut for OwnedAcl {
+    fn deref_mut(&mut self) -> &mut Acl {
+        &mut self.inner
+    }
+}
+
+/// Append entries with [`OwnedAcl::try_extend`]
+///
+/// # Panics
+///
+/// Panics if the ACL cannot grow to fit the entries: if allocation fails, or
+/// if the ACL would be larger than 64 KiB.
+impl<'s> Extend<AceEntry<'s>> for OwnedAcl {
+    fn extend<I: IntoIterator<Item = AceEntry<'s>>>(&mut self, entries: I) {
+        self.try_extend(entries)
+            .expect("Failed to extend ACL with entries")
+    }
+}
+
+impl AsRef<Acl> for OwnedAcl {
+    fn as_ref(&self) -> &Acl {
+        &self.inner
+    }
+}
+
+impl Clone for OwnedAcl {
+    fn clone(&self) -> Self {
+        // The source ACL is already valid, so this can only fail if
+        // allocation fails
+        Acl::to_owned(self).expect("Failed to clone ACL")
+    }
+}
+
+impl fmt::Debug for OwnedAcl {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, fmt)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{SecurityDescriptor, Sid};
+
+    #[test]
+    fn add_ace_grows() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)".parse()?;
+        let ace = sd.dacl().unwrap().get_ace(0).unwrap();
+
+        let mut acl = OwnedAcl::new()?;
+        for count in 1..=20 {
+            acl.add_ace(ace)?;
+            assert_eq!(acl.len(), count);
+            assert!(wrappers::IsValidAcl(&acl));
+        }
+
+        let system: LocalBox<Sid> = "SY".parse()?;
+        for index in 0..acl.len() {
+            assert_eq!(acl.get_ace(index).unwrap().sid(), Some(&*system));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn add_ace_grows_geometrically() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)".parse()?;
+        let ace = sd.dacl().unwrap().get_ace(0).unwrap();
+
+        let mut acl = OwnedAcl::new()?;
+        let mut capacity = acl.capacity();
+        let mut reallocations = 0;
+
+        for _ in 0..1000 {
+            acl.add_ace(ace)?;
+            if acl.capacity() != capacity {
+                assert!(acl.capacity() >= 2 * capacity);
+                capacity = acl.capacity();
+                reallocations += 1;
+            }
+        }
+
+        assert_eq!(acl.len(), 1000);
+        assert!(reallocations <= 12, "{} reallocations", reallocations);
+
+        Ok(())
+    }
+
+    #[test]
+    fn growth_is_capped_at_size_limit() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)".parse()?;
+        let ace = sd.dacl().unwrap().get_ace(0).unwrap();
+        let ace_size = ace.size() as u32;
+
+        // Fill an ACL that is more than half the limit, so doubling overshoots
+        let capacity = 40_000;
+        let mut acl = OwnedAcl::with_capacity(capacity)?;
+        let fits = (capacity - mem::size_of::<ACL>() as u32) / ace_size;
+        for _ in 0..fits {
+            acl.add_ace(ace)?;
+        }
+        assert_eq!(acl.capacity(), capacity);
+
+        acl.add_ace(ace)?;
+        assert_eq!(acl.capacity(), MAX_ACL_SIZE);
+
+        // Fill to the limit, after which adding fails without changing the ACL
+        let fits = (MAX_ACL_SIZE - mem::size_of::<ACL>() as u32) / ace_size;
+        while acl.len() < fits {
+            acl.add_ace(ace)?;
+        }
+        let err = acl.add_ace(ace).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(acl.len(), fits);
+        assert!(wrappers::IsValidAcl(&acl));
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_capacity_does_not_reallocate() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)".parse()?;
+        let ace = sd.dacl().unwrap().get_ace(0).unwrap();
+
+        let capacity = mem::size_of::<ACL>() as u32 + 10 * ace.size() as u32;
+        let mut acl = OwnedAcl::with_capacity(capacity)?;
+
+        for _ in 0..10 {
+            acl.add_ace(ace)?;
+            assert_eq!(acl.capacity(), capacity);
+        }
+
+        // One more forces the buffer to grow
+        acl.add_ace(ace)?;
+        assert!(acl.capacity() > capacity);
+        assert_eq!(acl.len(), 11);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reserve_does_not_reallocate() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)(A;;FR;;;WD)".parse()?;
+        let source = sd.dacl().unwrap();
+        let ace = source.get_ace(0).unwrap();
+
+        let mut acl = OwnedAcl::new()?;
+        acl.add_ace(source.get_ace(1).unwrap())?;
+        acl.reserve(10 * ace.size() as u32)?;
+
+        // Reserving keeps the existing ACEs and leaves a valid ACL
+        assert_eq!(acl.len(), 1);
+        assert!(wrappers::IsValidAcl(&acl));
+        assert_eq!(
+            acl.get_ace(0).unwrap().sid(),
+            source.get_ace(1).unwrap().sid()
+        );
+
+        let buffer = acl.as_bytes().as_ptr();
+        let capacity = acl.capacity();
+
+        for _ in 0..10 {
+            acl.add_ace(ace)?;
+            assert_eq!(acl.as_bytes().as_ptr(), buffer);
+            assert_eq!(acl.capacity(), capacity);
+        }
+
+        assert_eq!(acl.len(), 11);
+        assert!(wrappers::IsValidAcl(&acl));
+
+        // There is already enough room, so this does nothing
+        acl.reserve(0)?;
+        assert_eq!(acl.as_bytes().as_ptr(), buffer);
+
+        assert!(acl.reserve(u32::MAX).is_err());
+        assert!(acl.reserve(u16::MAX as u32).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn crafted_bytes_are_rejected() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)(A;;FR;;;WD)".parse()?;
+        let good = sd.dacl().unwrap().as_bytes().to_vec();
+
+        assert_eq!(OwnedAcl::copy_from_bytes(&good)?.len(), 2);
+
+        // Trailing bytes past AclSize are ignored
+        let mut padded = good.clone();
+        padded.extend_from_slice(&[0xff; 16]);
+        assert_eq!(
+            OwnedAcl::copy_from_bytes(&padded)?.capacity() as usize,
+            good.len()
+        );
+
+        let set_u16 = |bytes: &mut Vec<u8>, offset: usize, value: u16| {
+            bytes[offset..offset + 2].copy_from_slice(&value.to_le_bytes())
+        };
+
+        // More ACEs than the ACL holds
+        let mut too_many = good.clone();
+        set_u16(&mut too_many, 4, 3);
+
+        // Many more ACEs than the ACL holds
+        let mut far_too_many = good.clone();
+        set_u16(&mut far_too_many, 4, u16::MAX);
+
+        // First ACE runs past the end of the ACL
+        let mut long_ace = good.clone();
+        set_u16(&mut long_ace, 8 + 2, 0x1000);
+
+        // Zero-sized ACE
+        let mut empty_ace = good.clone();
+        set_u16(&mut empty_ace, 8 + 2, 0);
+
+        // AclSize larger than the buffer
+        let mut long_acl = good.clone();
+        set_u16(&mut long_acl, 2, good.len() as u16 + 4);
+
+        // AclSize smaller than the header
+        let mut short_acl = good.clone();
+        set_u16(&mut short_acl, 2, 4);
+
+        let cases = [
+            &good[..4],
+            &too_many[..],
+            &far_too_many[..],
+            &long_ace[..],
+            &empty_ace[..],
+            &long_acl[..],
+            &short_acl[..],
+            &good[..good.len() - 4],
+        ];
+
+        for bytes in cases.iter() {
+            let err = OwnedAcl::copy_from_bytes(bytes).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData, "{:?}", bytes);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn extend_with_entries() -> io::Result<()> {
+        use crate::constants::{AccessRights, AceFlags, AceType};
+
+        let sids: Vec<LocalBox<Sid>> = ["SY", "BA", "WD", "S-1-5-21-1-2-3-1001"]
+            .iter()
+            .map(|sid| sid.parse())
+            .collect::<io::Result<_>>()?;
+
+        let entries: Vec<AceEntry> = sids
+            .iter()
+            .enumerate()
+            .map(|(index, sid)| {
+                let ace_type = if index % 2 == 0 {
+                    AceType::ACCESS_ALLOWED_ACE_TYPE
+                } else {
+                    AceType::ACCESS_DENIED_ACE_TYPE
+                };
+                AceEntry::new(
+                    ace_type,
+                    AceFlags::ContainerInherit,
+                    AccessRights::FileGenericRead,
+                    sid,
+                )
+            })
+            .collect::<io::Result<_>>()?;
+
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)".parse()?;
+        let mut acl = OwnedAcl::new()?;
+        acl.add_ace(sd.dacl().unwrap().get_ace(0).unwrap())?;
+        acl.extend(entries.clone());
+
+        assert_eq!(acl.len(), 5);
+        assert!(wrappers::IsValidAcl(&acl));
+
+        for (ace, entry) in acl.aces().skip(1).zip(entries.iter()) {
+            assert_eq!(ace.ace_type(), entry.ace_type());
+            assert_eq!(ace.flags(), entry.flags());
+            assert_eq!(ace.mask(), entry.mask());
+            assert_eq!(ace.sid(), Some(entry.sid()));
+        }
+
+        // The whole batch fit in one reservation
+        let info = wrappers::GetAclInformationSize(&acl)?;
+        assert_eq!(info.bytes_free, 0);
+
+        // A batch past the 64 KiB limit fails without adding anything
+        let too_many = vec![entries[3]; 2000];
+        let err = acl.try_extend(too_many).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(acl.len(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to extend ACL with entries")]
+    fn extend_panics_past_size_limit() {
+        use crate::constants::{AccessRights, AceFlags};
+
+        let user: LocalBox<Sid> = "S-1-5-21-1-2-3-1001".parse().unwrap();
+        let entry = AceEntry::allow(&user, AccessRights::FileAllAccess, AceFlags::empty());
+        let mut acl = OwnedAcl::new().unwrap();
+
+        acl.extend(vec![entry; 2000]);
+    }
+
+    #[test]
+    fn clone_is_independent() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)".parse()?;
+        let ace = sd.dacl().unwrap().get_ace(0).unwrap();
+
+        let mut acl = OwnedAcl::new()?;
+        acl.add_ace(ace)?;
+        let copy = acl.clone();
+        acl.add_ace(ace)?;
+
+        assert_eq!(acl.len(), 2);
+        assert_eq!(copy.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_ace_at_index() -> io::Result<()> {
+        use crate::constants::{AccessRights, AceFlags, AceType};
+
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)(A;;FA;;;BA)(A;;FR;;;WD)".parse()?;
+        let mut acl = sd.dacl().unwrap().to_owned()?;
+        let sids: Vec<&Sid> = sd
+            .dacl()
+            .unwrap()
+            .aces()
+            .map(|ace| ace.sid().unwrap())
+            .collect();
+
+        let guests: LocalBox<Sid> = "BG".parse()?;
+        let entry = AceEntry::deny(&guests, AccessRights::FileAllAccess, AceFlags::empty());
+
+        acl.insert_ace(0, &entry)?;
+
+        assert_eq!(acl.len(), 4);
+        assert!(wrappers::IsValidAcl(&acl));
+
+        let first = acl.get_ace(0).unwrap();
+        assert_eq!(first.ace_type(), AceType::ACCESS_DENIED_ACE_TYPE);
+        assert_eq!(first.mask(), AccessRights::FileAllAccess);
+        assert_eq!(first.sid(), Some(&*guests));
+
+        // The allow ACEs keep their order after it
+        for (ace, sid) in acl.aces().skip(1).zip(sids.iter()) {
+            assert_eq!(ace.ace_type(), AceType::ACCESS_ALLOWED_ACE_TYPE);
+            assert_eq!(ace.sid(), Some(*sid));
+        }
+
+        // Inserting at the end appends
+        acl.insert_ace(4, &entry)?;
+        assert_eq!(acl.len(), 5);
+        assert_eq!(acl.get_ace(4).unwrap().sid(), Some(&*guests));
+
+        let err = acl.insert_ace(6, &entry).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(acl.len(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_all_inherit() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;NP;FA;;;SY)(D;CI;FW;;;BG)".parse()?;
+        let mut acl = sd.dacl().unwrap().to_owned()?;
+
+        acl.set_all_inherit(true, true)?;
+
+        let inherit = AceFlags::ContainerInherit | AceFlags::ObjectInherit;
+        assert_eq!(acl.len(), 2);
+        assert_eq!(
+            acl.get_ace(0).unwrap().flags(),
+            inherit | AceFlags::NoPropagateInherit
+        );
+        assert_eq!(acl.get_ace(1).unwrap().flags(), inherit);
+        assert!(wrappers::IsValidAcl(&acl));
+
+        // Clearing leaves the other flags alone
+        acl.set_all_inherit(false, false)?;
+        assert_eq!(
+            acl.get_ace(0).unwrap().flags(),
+            AceFlags::NoPropagateInherit
+        );
+        assert_eq!(acl.get_ace(1).unwrap().flags(), AceFlags::empty());
+
+        // The source ACL is untouched
+        assert_eq!(
+            sd.dacl().unwrap().get_ace(1).unwrap().flags(),
+            AceFlags::ContainerInherit
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn retain() -> io::Result<()> {
+        use crate::constants::AceType;
+
+        let sd: LocalBox<SecurityDescriptor> =
+            "D:(D;;FW;;;BG)(A;;FA;;;SY)(D;;FA;;;AN)(A;;FA;;;BA)(A;;FR;;;WD)".parse()?;
+        let mut acl = sd.dacl().unwrap().to_owned()?;
+
+        let mut visited = Vec::new();
+        acl.retain(|ace| {
+            visited.push(ace.sid().unwrap().to_string());
+            ace.ace_type() == AceType::ACCESS_ALLOWED_ACE_TYPE
+        })?;
+
+        // Every ACE was seen once, in order
+        let sids: Vec<String> = sd
+            .dacl()
+            .unwrap()
+            .aces()
+            .map(|ace| ace.sid().unwrap().to_string())
+            .collect();
+        assert_eq!(visited, sids);
+
+        assert!(wrappers::IsValidAcl(&acl));
+        assert_eq!(
+            sd.with_dacl(&acl)?.as_sddl()?,
+            "D:(A;;FA;;;SY)(A;;FA;;;BA)(A;;FR;;;WD)"
+        );
+
+        // Keeping everything and keeping nothing both work
+        acl.retain(|_| true)?;
+        assert_eq!(acl.len(), 3);
+        acl.retain(|_| false)?;
+        assert_eq!(acl.len(), 0);
+
+        Ok(())
+    }
+}
@@ -1,8 +1,23 @@
-use crate::constants::SecurityInformation;
-use crate::{wrappers, Acl, LocalBox, Sid};
+use crate::constants::{
+    AccessRights, AceType, IntegrityLevel, SdControl, SddlRevision, SeObjectType,
+    SecurityImpersonationLevel, SecurityInformation,
+};
+use crate::ffi::shared::winerror::{
+    ERROR_NOT_ALL_ASSIGNED, ERROR_NO_TOKEN, ERROR_PRIVILEGE_NOT_HELD,
+};
+use crate::ffi::um::winnt::{
+    SECURITY_DESCRIPTOR_RELATIVE, SECURITY_DESCRIPTOR_REVISION, SECURITY_MANDATORY_LABEL_AUTHORITY,
+    SE_SECURITY_NAME, TOKEN_ADJUST_PRIVILEGES, TOKEN_IMPERSONATE, TOKEN_QUERY,
+};
+use crate::sddl::{self, SddlError, SddlSpans};
+use crate::{
+    wrappers, Ace, Acl, GenericMapping, Handle, LocalBox, ObjectTypeListEntry, OwnedAcl, Sid,
+};
 use std::ffi::OsString;
 use std::fmt;
 use std::io;
+use std::mem;
+use std::path::Path;
 use std::str::FromStr;
 
 /// A Windows security descriptor.
@@ -23,6 +38,93 @@ impl Drop for SecurityDescriptor {
 }
 
 impl SecurityDescriptor {
+    /// Create an absolute security descriptor with no components
+    ///
+    /// The descriptor has no owner, group, DACL, or SACL.
+    ///
+    /// ```
+    /// use windows_permissions::SecurityDescriptor;
+    ///
+    /// let sd = SecurityDescriptor::empty().unwrap();
+    ///
+    /// assert_eq!(sd.owner(), None);
+    /// assert!(sd.dacl().is_none());
+    /// assert_eq!(sd.as_sddl().unwrap(), "");
+    /// ```
+    pub fn empty() -> io::Result<LocalBox<SecurityDescriptor>> {
+        wrappers::InitializeSecurityDescriptor()
+    }
+
+    /// Create a security descriptor with a null DACL, which allows all access
+    ///
+    /// A null DACL is different from an empty DACL: with no DACL at all, the
+    /// object is not protected, and everyone is granted full access. Compare
+    /// [`deny_all`](Self::deny_all).
+    ///
+    /// ```
+    /// use windows_permissions::SecurityDescriptor;
+    ///
+    /// let sd = SecurityDescriptor::allow_all().unwrap();
+    ///
+    /// assert!(sd.grants_everyone());
+    /// assert_eq!(sd.as_sddl().unwrap(), "D:NO_ACCESS_CONTROL");
+    /// ```
+    pub fn allow_all() -> io::Result<LocalBox<SecurityDescriptor>> {
+        let mut sd = wrappers::InitializeSecurityDescriptor()?;
+
+        // Safety: sd is absolute, and a null DACL is not a pointer
+        unsafe { wrappers::SetSecurityDescriptorDacl(&mut sd, true, None, false)? };
+
+        wrappers::MakeSelfRelativeSD(&sd)
+    }
+
+    /// Create a security descriptor with an empty DACL, which denies all access
+    ///
+    /// An empty DACL has no ACEs, so no one is granted any access (although
+    /// the owner can still read and change the DACL). Compare
+    /// [`allow_all`](Self::allow_all).
+    ///
+    /// ```
+    /// use windows_permissions::SecurityDescriptor;
+    ///
+    /// let sd = SecurityDescriptor::deny_all().unwrap();
+    ///
+    /// assert!(!sd.grants_everyone());
+    /// assert_eq!(sd.dacl().unwrap().len(), 0);
+    /// assert_eq!(sd.as_sddl().unwrap(), "D:");
+    /// ```
+    pub fn deny_all() -> io::Result<LocalBox<SecurityDescriptor>> {
+        let mut sd = wrappers::InitializeSecurityDescriptor()?;
+        let dacl = OwnedAcl::new()?;
+
+        // Safety: dacl outlives the absolute sd, which is only used to build
+        // the self-relative copy
+        unsafe { wrappers::SetSecurityDescriptorDacl(&mut sd, true, Some(&dacl), false)? };
+
+        wrappers::MakeSelfRelativeSD(&sd)
+    }
+
+    /// Check whether the descriptor has a null DACL, so it grants all access
+    ///
+    /// This is `false` when there is no DACL at all, since the object's
+    /// access is then decided elsewhere (for example, by inheritance or a
+    /// default DACL), and when there is a DACL, even if it allows everyone.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let null: LocalBox<SecurityDescriptor> = "D:NO_ACCESS_CONTROL".parse().unwrap();
+    /// let missing: LocalBox<SecurityDescriptor> = "O:SY".parse().unwrap();
+    /// let everyone: LocalBox<SecurityDescriptor> = "D:(A;;GA;;;WD)".parse().unwrap();
+    ///
+    /// assert!(null.grants_everyone());
+    /// assert!(!missing.grants_everyone());
+    /// assert!(!everyone.grants_everyone());
+    /// ```
+    pub fn grants_everyone(&self) -> bool {
+        self.control().contains(SdControl::DaclPresent) && self.dacl().is_none()
+    }
+
     /// Get the Security Descriptor Definition Language (SDDL) string
     /// corresponding to this `SecurityDescriptor`
     ///
@@ -31,12 +133,214 @@ impl SecurityDescriptor {
     /// `wrappers::ConvertSecurityDescriptorToStringSecurityDescriptor`
     /// directly.
     pub fn as_sddl(&self) -> io::Result<OsString> {
-        wrappers::ConvertSecurityDescriptorToStringSecurityDescriptor(
+        self.as_sddl_revision(SddlRevision::default())
+    }
+
+    /// Get the SDDL string corresponding to this `SecurityDescriptor`, using
+    /// a specific SDDL revision
+    ///
+    /// [`SecurityDescriptor::as_sddl`] uses the default revision. Pinning the
+    /// revision keeps the output format stable if later revisions are added.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    /// use windows_permissions::constants::SddlRevision;
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     sd.as_sddl_revision(SddlRevision::SDDL_REVISION_1).unwrap(),
+    ///     "O:SYD:(A;;FA;;;WD)"
+    /// );
+    /// ```
+    pub fn as_sddl_revision(&self, revision: SddlRevision) -> io::Result<OsString> {
+        wrappers::ConvertSecurityDescriptorToStringSecurityDescriptorWithRevision(
             self,
             SecurityInformation::all(),
+            revision,
+        )
+    }
+
+    /// Check whether an SDDL string can be parsed, and find the problem if not
+    ///
+    /// Parsing with `FromStr` only gives the bare Windows error. This
+    /// reports the kind of problem and, where it can be found, the offset of
+    /// the offending component. See the [`sddl`](crate::sddl) module for
+    /// details.
+    ///
+    /// ```
+    /// use windows_permissions::SecurityDescriptor;
+    /// use windows_permissions::sddl::SddlErrorKind;
+    ///
+    /// assert!(SecurityDescriptor::validate_sddl("D:(A;;FA;;;WD)").is_ok());
+    ///
+    /// let err = SecurityDescriptor::validate_sddl("D:(A;;FA;;;XY)").unwrap_err();
+    /// assert_eq!(err.kind, SddlErrorKind::UnknownMnemonic);
+    /// assert_eq!(err.offset, Some(11));
+    /// ```
+    pub fn validate_sddl(sddl: &str) -> Result<(), SddlError> {
+        sddl::validate(sddl)
+    }
+
+    /// Find where the owner, group, DACL, and SACL are in an SDDL string
+    ///
+    /// The string must be valid SDDL. See [`sddl::spans`] for details.
+    ///
+    /// ```
+    /// use windows_permissions::SecurityDescriptor;
+    ///
+    /// let sddl = "O:SYG:BAD:(A;;FA;;;WD)";
+    /// let spans = SecurityDescriptor::sddl_spans(sddl).unwrap();
+    ///
+    /// assert_eq!(&sddl[spans.group.unwrap()], "G:BA");
+    /// assert_eq!(spans.sacl, None);
+    /// ```
+    pub fn sddl_spans(sddl: &str) -> io::Result<SddlSpans> {
+        sddl::spans(sddl)
+    }
+
+    /// Get a `Debug` view of this descriptor that hides account RIDs
+    ///
+    /// The output shows the owner, group, control flags, and every ACE's
+    /// type, flags, mask, and SID. Account SIDs (see
+    /// [`Sid::is_domain_account`]) that end in a RID after the three domain
+    /// sub-authorities have the RID replaced with `****`, so
+    /// the logged output shows the descriptor's shape without identifying
+    /// the specific accounts. Well-known SIDs are shown as-is.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> =
+    ///     "O:S-1-5-21-1-2-3-1001D:(A;;FA;;;SY)".parse().unwrap();
+    /// let debug = format!("{:?}", sd.debug_redacted());
+    ///
+    /// assert!(debug.contains("S-1-5-21-1-2-3-****"));
+    /// assert!(debug.contains("S-1-5-18"));
+    /// assert!(!debug.contains("1001"));
+    /// ```
+    pub fn debug_redacted(&self) -> impl fmt::Debug + '_ {
+        Redacted(self)
+    }
+
+    /// Read the owner, group, and DACL of a file or directory
+    ///
+    /// This is the set of information usually needed to audit who can access
+    /// a file. Use [`of_file_with_sacl`](Self::of_file_with_sacl) to also
+    /// read the SACL.
+    ///
+    /// Errors keep the underlying Windows error code. A missing file or
+    /// directory gives an error of kind [`io::ErrorKind::NotFound`], and a
+    /// file the caller cannot read the permissions of gives
+    /// [`io::ErrorKind::PermissionDenied`].
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use windows_permissions::SecurityDescriptor;
+    ///
+    /// let sd = SecurityDescriptor::of_file(Path::new(r"C:\Windows")).unwrap();
+    /// println!("{:?}", sd.owner());
+    /// ```
+    pub fn of_file(path: &Path) -> io::Result<LocalBox<SecurityDescriptor>> {
+        wrappers::GetNamedSecurityInfo(
+            path,
+            SeObjectType::SE_FILE_OBJECT,
+            SecurityInformation::Owner | SecurityInformation::Group | SecurityInformation::Dacl,
         )
     }
 
+    /// Read the owner, group, DACL, and SACL of a file or directory
+    ///
+    /// Reading a SACL requires `SeSecurityPrivilege`. For the duration of the
+    /// call, the current thread impersonates a copy of the process token with
+    /// the privilege enabled, so other threads are not affected. If the
+    /// process does not hold the privilege (usually because it is not running
+    /// elevated), this fails with `ERROR_PRIVILEGE_NOT_HELD` before the file
+    /// is opened.
+    ///
+    /// Other errors are reported as in [`of_file`](Self::of_file).
+    pub fn of_file_with_sacl(path: &Path) -> io::Result<LocalBox<SecurityDescriptor>> {
+        with_privilege(SE_SECURITY_NAME, || {
+            wrappers::GetNamedSecurityInfo(
+                path,
+                SeObjectType::SE_FILE_OBJECT,
+                SecurityInformation::Owner
+                    | SecurityInformation::Group
+                    | SecurityInformation::Dacl
+                    | SecurityInformation::Sacl,
+            )
+        })
+    }
+
+    /// Get the raw bytes of a self-relative descriptor
+    ///
+    /// Returns `None` for absolute descriptors, whose components are stored
+    /// elsewhere in memory. Use [`wrappers::MakeSelfRelativeSD`] to get a
+    /// self-relative copy first.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse().unwrap();
+    /// let bytes = sd.as_bytes().unwrap();
+    ///
+    /// // Byte 0 is the revision
+    /// assert_eq!(bytes[0], 1);
+    ///
+    /// assert_eq!(SecurityDescriptor::empty().unwrap().as_bytes(), None);
+    /// ```
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        if !self.control().contains(SdControl::SelfRelative) {
+            return None;
+        }
+
+        let len = wrappers::GetSecurityDescriptorLength(self) as usize;
+        Some(unsafe { std::slice::from_raw_parts(self as *const _ as *const u8, len) })
+    }
+
+    /// Parse a self-relative security descriptor from raw bytes, such as
+    /// those from [`as_bytes`](Self::as_bytes)
+    ///
+    /// Only self-relative descriptors are accepted. An absolute descriptor
+    /// holds pointers to its owner, group, and ACLs rather than offsets, and
+    /// those pointers mean nothing outside the process that made them, so
+    /// bytes without the `SE_SELF_RELATIVE` control bit are rejected instead
+    /// of being misread.
+    ///
+    /// The bytes do not have to be trusted: every component is bounds-checked
+    /// and the copy is validated by Windows. Any problem gives an error of
+    /// kind [`io::ErrorKind::InvalidData`].
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse().unwrap();
+    /// let bytes = sd.as_bytes().unwrap().to_vec();
+    ///
+    /// let copy = SecurityDescriptor::from_bytes(&bytes).unwrap();
+    /// assert_eq!(copy.as_sddl().unwrap(), sd.as_sddl().unwrap());
+    ///
+    /// assert!(SecurityDescriptor::from_bytes(&bytes[..10]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<LocalBox<SecurityDescriptor>> {
+        check_relative_bounds(bytes)?;
+
+        let sd = unsafe {
+            let sd: LocalBox<SecurityDescriptor> = LocalBox::try_allocate(false, bytes.len())?;
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), sd.as_ptr() as *mut u8, bytes.len());
+            sd
+        };
+
+        if !wrappers::IsValidSecurityDescriptor(&sd) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Copied security descriptor failed validation",
+            ));
+        }
+
+        Ok(sd)
+    }
+
     /// Get the owner SID if it exists
     ///
     /// ```
@@ -72,16 +376,316 @@ impl SecurityDescriptor {
     }
 
     /// Get the DACL if it exists
+    ///
+    /// Returns `None` both when there is no DACL and when there is a null DACL.
+    /// Use [`grants_everyone`](Self::grants_everyone) to tell them apart.
     pub fn dacl(&self) -> Option<&Acl> {
         wrappers::GetSecurityDescriptorDacl(self)
             .expect("Valid SecurityDescriptor failed to get dacl")
     }
 
     /// Get the SACL if it exists
+    ///
+    /// Returns `None` both when there is no SACL and when there is a null SACL.
     pub fn sacl(&self) -> Option<&Acl> {
         wrappers::GetSecurityDescriptorSacl(self)
             .expect("Valid SecurityDescriptor failed to get sacl")
     }
+
+    /// Check whether the owner was set by a default mechanism
+    ///
+    /// Windows marks an owner as defaulted when it came from a default
+    /// source, such as the creator's token, rather than being set
+    /// explicitly.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse().unwrap();
+    ///
+    /// assert!(!sd.owner_defaulted());
+    /// ```
+    pub fn owner_defaulted(&self) -> bool {
+        self.control().contains(SdControl::OwnerDefaulted)
+    }
+
+    /// Check whether the group was set by a default mechanism
+    ///
+    /// See [`owner_defaulted`](Self::owner_defaulted).
+    pub fn group_defaulted(&self) -> bool {
+        self.control().contains(SdControl::GroupDefaulted)
+    }
+
+    /// Check whether the DACL was set by a default mechanism
+    ///
+    /// See [`owner_defaulted`](Self::owner_defaulted).
+    pub fn dacl_defaulted(&self) -> bool {
+        self.control().contains(SdControl::DaclDefaulted)
+    }
+
+    /// Check whether the SACL was set by a default mechanism
+    ///
+    /// See [`owner_defaulted`](Self::owner_defaulted).
+    pub fn sacl_defaulted(&self) -> bool {
+        self.control().contains(SdControl::SaclDefaulted)
+    }
+
+    /// Get the integrity level set by the first mandatory label in the SACL
+    ///
+    /// Returns `None` if there is no SACL, it has no mandatory label, or the
+    /// label is not a known integrity level. Mandatory labels are only read
+    /// from an object when `SecurityInformation::Label` is requested.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    /// use windows_permissions::constants::IntegrityLevel;
+    ///
+    /// let low: LocalBox<SecurityDescriptor> = "S:(ML;;NW;;;LW)".parse().unwrap();
+    /// let none: LocalBox<SecurityDescriptor> = "O:SY".parse().unwrap();
+    ///
+    /// assert_eq!(low.integrity_level(), Some(IntegrityLevel::SECURITY_MANDATORY_LOW_RID));
+    /// assert_eq!(none.integrity_level(), None);
+    /// ```
+    pub fn integrity_level(&self) -> Option<IntegrityLevel> {
+        let label = self
+            .sacl()?
+            .aces()
+            .find(|ace| ace.ace_type() == AceType::SYSTEM_MANDATORY_LABEL_ACE_TYPE)?
+            .sid()?;
+
+        if *label.id_authority() != SECURITY_MANDATORY_LABEL_AUTHORITY {
+            return None;
+        }
+
+        label
+            .sub_authority(label.sub_authority_count().checked_sub(1)?)
+            .and_then(IntegrityLevel::from_raw)
+    }
+
+    /// Get the control bits of the security descriptor
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    /// use windows_permissions::constants::SdControl;
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "D:PAI(A;;FA;;;WD)".parse().unwrap();
+    ///
+    /// assert!(sd.control().contains(SdControl::DaclProtected));
+    /// assert!(sd.control().contains(SdControl::DaclAutoInherited));
+    /// assert!(!sd.control().contains(SdControl::SaclPresent));
+    /// ```
+    pub fn control(&self) -> SdControl {
+        wrappers::GetSecurityDescriptorControl(self)
+            .expect("Valid SecurityDescriptor failed to get control")
+    }
+
+    /// Get the resource manager control bits, if they are set
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let mut sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;WD)".parse().unwrap();
+    /// assert_eq!(sd.rm_control(), None);
+    ///
+    /// sd.set_rm_control(Some(7));
+    /// assert_eq!(sd.rm_control(), Some(7));
+    /// ```
+    pub fn rm_control(&self) -> Option<u8> {
+        wrappers::GetSecurityDescriptorRMControl(self)
+            .expect("Valid SecurityDescriptor failed to get RM control")
+    }
+
+    /// Set or clear the resource manager control bits
+    ///
+    /// This also sets or clears [`SdControl::RmControlValid`].
+    pub fn set_rm_control(&mut self, value: Option<u8>) {
+        wrappers::SetSecurityDescriptorRMControl(self, value)
+            .expect("Valid SecurityDescriptor failed to set RM control")
+    }
+
+    /// Create a copy of this descriptor with a different owner
+    ///
+    /// The `SetSecurityDescriptor*` family of functions only work on absolute
+    /// security descriptors. This converts to absolute format, sets the owner,
+    /// and converts back, returning a new self-relative descriptor. Everything
+    /// else, including control bits, is carried over unchanged.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor, Sid};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse().unwrap();
+    /// let new_owner: LocalBox<Sid> = "BA".parse().unwrap();
+    ///
+    /// let sd2 = sd.with_owner(&new_owner).unwrap();
+    ///
+    /// assert_eq!(sd2.owner(), Some(&*new_owner));
+    /// assert_eq!(sd2.as_sddl().unwrap(), "O:BAD:(A;;FA;;;WD)");
+    /// ```
+    pub fn with_owner(&self, owner: &Sid) -> io::Result<LocalBox<SecurityDescriptor>> {
+        let mut absolute = self.to_absolute()?;
+
+        // Safety: absolute is dropped before owner is
+        unsafe { wrappers::SetSecurityDescriptorOwner(&mut absolute, Some(owner), false)? };
+
+        wrappers::MakeSelfRelativeSD(&absolute)
+    }
+
+    /// Create a copy of this descriptor with a different group
+    ///
+    /// See [`SecurityDescriptor::with_owner`] for details.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor, Sid};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "O:SYG:SY".parse().unwrap();
+    /// let new_group: LocalBox<Sid> = "BA".parse().unwrap();
+    ///
+    /// let sd2 = sd.with_group(&new_group).unwrap();
+    ///
+    /// assert_eq!(sd2.as_sddl().unwrap(), "O:SYG:BA");
+    /// ```
+    pub fn with_group(&self, group: &Sid) -> io::Result<LocalBox<SecurityDescriptor>> {
+        let mut absolute = self.to_absolute()?;
+
+        // Safety: absolute is dropped before group is
+        unsafe { wrappers::SetSecurityDescriptorGroup(&mut absolute, Some(group), false)? };
+
+        wrappers::MakeSelfRelativeSD(&absolute)
+    }
+
+    /// Create a copy of this descriptor with a different DACL
+    ///
+    /// See [`SecurityDescriptor::with_owner`] for details.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse().unwrap();
+    /// let other: LocalBox<SecurityDescriptor> = "D:(A;;FR;;;BA)".parse().unwrap();
+    ///
+    /// let sd2 = sd.with_dacl(other.dacl().unwrap()).unwrap();
+    ///
+    /// assert_eq!(sd2.as_sddl().unwrap(), "O:SYD:(A;;FR;;;BA)");
+    /// ```
+    pub fn with_dacl(&self, dacl: &Acl) -> io::Result<LocalBox<SecurityDescriptor>> {
+        let mut absolute = self.to_absolute()?;
+
+        // Safety: absolute is dropped before dacl is
+        unsafe { wrappers::SetSecurityDescriptorDacl(&mut absolute, true, Some(dacl), false)? };
+
+        wrappers::MakeSelfRelativeSD(&absolute)
+    }
+
+    /// Create a copy of this descriptor with a different SACL
+    ///
+    /// See [`SecurityDescriptor::with_owner`] for details.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let sd: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse().unwrap();
+    /// let other: LocalBox<SecurityDescriptor> = "S:(AU;FA;FA;;;WD)".parse().unwrap();
+    ///
+    /// let sd2 = sd.with_sacl(other.sacl().unwrap()).unwrap();
+    ///
+    /// assert_eq!(sd2.as_sddl().unwrap(), "O:SYD:(A;;FA;;;WD)S:(AU;FA;FA;;;WD)");
+    /// ```
+    pub fn with_sacl(&self, sacl: &Acl) -> io::Result<LocalBox<SecurityDescriptor>> {
+        let mut absolute = self.to_absolute()?;
+
+        // Safety: absolute is dropped before sacl is
+        unsafe { wrappers::SetSecurityDescriptorSacl(&mut absolute, true, Some(sacl), false)? };
+
+        wrappers::MakeSelfRelativeSD(&absolute)
+    }
+
+    /// Get a normalized copy of this descriptor for comparison
+    ///
+    /// The DACL and SACL are put in a deterministic order and exact duplicate
+    /// ACEs are removed. Explicit ACEs come before inherited ones, and within
+    /// each group access-denied ACEs come first, which is also the order
+    /// Windows expects. The owner, group, and control bits are unchanged.
+    ///
+    /// The result is always self-relative, so two descriptors with the same
+    /// ACEs in a different order normalize to the same bytes.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, SecurityDescriptor};
+    ///
+    /// let sd1: LocalBox<SecurityDescriptor> =
+    ///     "O:SYD:(A;;FR;;;WD)(D;;FW;;;BG)(A;;FR;;;WD)".parse().unwrap();
+    /// let sd2: LocalBox<SecurityDescriptor> = "O:SYD:(D;;FW;;;BG)(A;;FR;;;WD)".parse().unwrap();
+    ///
+    /// let normalized = sd1.normalize().unwrap();
+    ///
+    /// assert_eq!(normalized.as_sddl().unwrap(), "O:SYD:(D;;FW;;;BG)(A;;FR;;;WD)");
+    /// assert_eq!(normalized.as_bytes(), sd2.normalize().unwrap().as_bytes());
+    /// ```
+    pub fn normalize(&self) -> io::Result<LocalBox<SecurityDescriptor>> {
+        let mut absolute = self.to_absolute()?;
+        let control = self.control();
+
+        let dacl = self.dacl().map(Acl::normalized).transpose()?;
+        let sacl = self.sacl().map(Acl::normalized).transpose()?;
+
+        // Safety: absolute points into dacl and sacl once they are set, but it
+        // is not used after MakeSelfRelativeSD copies them out, which happens
+        // while both are still alive. Null and missing ACLs are left alone.
+        unsafe {
+            if let Some(dacl) = &dacl {
+                let defaulted = control.contains(SdControl::DaclDefaulted);
+                wrappers::SetSecurityDescriptorDacl(&mut absolute, true, Some(dacl), defaulted)?;
+            }
+
+            if let Some(sacl) = &sacl {
+                let defaulted = control.contains(SdControl::SaclDefaulted);
+                wrappers::SetSecurityDescriptorSacl(&mut absolute, true, Some(sacl), defaulted)?;
+            }
+        }
+
+        wrappers::MakeSelfRelativeSD(&absolute)
+    }
+
+    /// Check the access granted to each node of an object type tree
+    ///
+    /// `token` must be an impersonation token opened with `TOKEN_QUERY`
+    /// access. Any generic rights in `desired` are mapped using `mapping`
+    /// before the check. The descriptor must have an owner and a group.
+    ///
+    /// The result holds the access granted to each entry of `object_types`,
+    /// in order. Nodes that were denied have an empty mask. To get the reason
+    /// for each denial, use `wrappers::AccessCheckByTypeResultList` directly.
+    pub fn access_check_by_type(
+        &self,
+        token: &Handle,
+        object_types: &[ObjectTypeListEntry],
+        desired: AccessRights,
+        mapping: &GenericMapping,
+    ) -> io::Result<Vec<AccessRights>> {
+        let results = wrappers::AccessCheckByTypeResultList(
+            self,
+            None,
+            token,
+            mapping.map(desired),
+            object_types,
+            mapping,
+        )?;
+
+        Ok(results
+            .into_iter()
+            .map(|result| result.unwrap_or_else(|_| AccessRights::empty()))
+            .collect())
+    }
+
+    /// Get an absolute copy of this descriptor, whatever its current format
+    fn to_absolute(&self) -> io::Result<LocalBox<SecurityDescriptor>> {
+        if self.control().contains(SdControl::SelfRelative) {
+            wrappers::MakeAbsoluteSD(self)
+        } else {
+            let relative = wrappers::MakeSelfRelativeSD(self)?;
+            wrappers::MakeAbsoluteSD(&relative)
+        }
+    }
 }
 
 impl fmt::Debug for SecurityDescriptor {
@@ -94,6 +698,163 @@ impl fmt::Debug for SecurityDescriptor {
     }
 }
 
+/// Check that the header and every component of a self-relative security
+/// descriptor fit in `bytes`
+///
+/// Windows follows the offsets in the header without checking them, so this
+/// walks them without calling into Windows.
+fn check_relative_bounds(bytes: &[u8]) -> io::Result<()> {
+    let invalid = |message: String| Err(io::Error::new(io::ErrorKind::InvalidData, message));
+    let header = mem::size_of::<SECURITY_DESCRIPTOR_RELATIVE>();
+
+    if bytes.len() < header {
+        return invalid(format!(
+            "Security descriptor needs at least {} bytes, got {}",
+            header,
+            bytes.len()
+        ));
+    }
+
+    if bytes[0] as u32 != SECURITY_DESCRIPTOR_REVISION {
+        return invalid(format!("Unknown security descriptor revision {}", bytes[0]));
+    }
+
+    let control = SdControl::from_bits_truncate(u16::from_le_bytes([bytes[2], bytes[3]]));
+    if !control.contains(SdControl::SelfRelative) {
+        return invalid(
+            "Security descriptor is absolute, not self-relative; \
+             its pointers cannot be read from bytes"
+                .to_string(),
+        );
+    }
+
+    let offset = |at: usize| {
+        u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]) as usize
+    };
+
+    for (name, at) in [("Owner", 4), ("Group", 8)] {
+        let start = offset(at);
+        if start == 0 {
+            continue;
+        }
+
+        // A SID is 8 bytes plus 4 per sub-authority, and byte 1 is the count
+        let end = start
+            .checked_add(8)
+            .filter(|&fixed_end| start >= header && fixed_end <= bytes.len())
+            .and_then(|fixed_end| fixed_end.checked_add(4 * bytes[start + 1] as usize));
+        if !matches!(end, Some(end) if end <= bytes.len()) {
+            return invalid(format!("{} SID does not fit in the buffer", name));
+        }
+    }
+
+    for (name, at) in [("SACL", 12), ("DACL", 16)] {
+        let start = offset(at);
+        if start == 0 {
+            continue;
+        }
+
+        if start < header || start > bytes.len() {
+            return invalid(format!("{} does not fit in the buffer", name));
+        }
+
+        Acl::from_bytes(&bytes[start..]).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid {}: {}", name, e),
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Run `f` with a privilege enabled for the current thread
+///
+/// The thread impersonates a copy of the process token, and the privilege is
+/// only enabled on that copy, so other threads are not affected. Afterwards
+/// the thread goes back to the token it had before, which may itself be an
+/// impersonation token.
+fn with_privilege<T>(name: &str, f: impl FnOnce() -> io::Result<T>) -> io::Result<T> {
+    let previous = match wrappers::OpenThreadToken(TOKEN_IMPERSONATE) {
+        Ok(token) => Some(token),
+        Err(e) if e.raw_os_error() == Some(ERROR_NO_TOKEN as i32) => None,
+        Err(e) => return Err(e),
+    };
+    let luid = wrappers::LookupPrivilegeValue(name)?;
+
+    wrappers::ImpersonateSelf(SecurityImpersonationLevel::SecurityImpersonation)?;
+
+    let result = wrappers::OpenThreadToken(TOKEN_ADJUST_PRIVILEGES | TOKEN_QUERY)
+        .and_then(
+            |token| match wrappers::AdjustTokenPrivileges(&token, luid, true) {
+                Ok(_) => Ok(()),
+                Err(e) if e.raw_os_error() == Some(ERROR_NOT_ALL_ASSIGNED as i32) => Err(
+                    io::Error::from_raw_os_error(ERROR_PRIVILEGE_NOT_HELD as i32),
+                ),
+                Err(e) => Err(e),
+            },
+        )
+        .and_then(|()| f());
+
+    // A thread left impersonating is worse than losing the result of `f`
+    wrappers::SetThreadToken(previous.as_ref()).and(result)
+}
+
+/// `Debug` view of a descriptor that hides account RIDs
+struct Redacted<'a, T: ?Sized>(&'a T);
+
+impl Redacted<'_, Sid> {
+    fn sid_string(&self) -> String {
+        match self.0.split_rid() {
+            // S-1-5-21-X-Y-Z is the domain itself; only a fifth sub-authority is a RID
+            Some((domain, _))
+                if self.0.is_domain_account() && self.0.sub_authority_count() >= 5 =>
+            {
+                format!("{}-****", domain)
+            }
+            _ => self.0.to_string(),
+        }
+    }
+}
+
+impl fmt::Debug for Redacted<'_, Sid> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(&self.sid_string())
+    }
+}
+
+impl fmt::Debug for Redacted<'_, Acl> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_list()
+            .entries(self.0.aces().map(Redacted))
+            .finish()
+    }
+}
+
+impl fmt::Debug for Redacted<'_, Ace> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_map()
+            .entry(&"ace_type", &self.0.ace_type())
+            .entry(&"flags", &self.0.flags())
+            .entry(&"mask", &self.0.mask())
+            .entry(&"sid", &self.0.sid().map(Redacted))
+            .finish()
+    }
+}
+
+impl fmt::Debug for Redacted<'_, SecurityDescriptor> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_map()
+            .entry(&"owner", &self.0.owner().map(Redacted))
+            .entry(&"group", &self.0.group().map(Redacted))
+            .entry(&"control", &self.0.control())
+            .entry(&"dacl", &self.0.dacl().map(Redacted))
+            .entry(&"sacl", &self.0.sacl().map(Redacted))
+            .finish()
+    }
+}
+
 impl FromStr for LocalBox<SecurityDescriptor> {
     type Err = io::Error;
 
@@ -185,4 +946,516 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn integrity_level() -> io::Result<()> {
+        use crate::constants::MandatoryPolicy;
+
+        let mut sacl = OwnedAcl::new()?;
+        sacl.add_mandatory_label(
+            IntegrityLevel::SECURITY_MANDATORY_LOW_RID,
+            MandatoryPolicy::NoWriteUp | MandatoryPolicy::NoReadUp,
+        )?;
+
+        assert_eq!(sacl.len(), 1);
+        let label = sacl.get_ace(0).unwrap();
+        assert_eq!(label.ace_type(), AceType::SYSTEM_MANDATORY_LABEL_ACE_TYPE);
+        assert_eq!(
+            label.mask(),
+            AccessRights::MandatoryLabelNoWriteUp | AccessRights::MandatoryLabelNoReadUp
+        );
+
+        let sd: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse()?;
+        let labelled = sd.with_sacl(&sacl)?;
+
+        assert_eq!(
+            labelled.integrity_level(),
+            Some(IntegrityLevel::SECURITY_MANDATORY_LOW_RID)
+        );
+        assert_eq!(sd.integrity_level(), None);
+
+        let parsed: LocalBox<SecurityDescriptor> = labelled.as_sddl()?.to_str().unwrap().parse()?;
+        assert_eq!(
+            parsed.integrity_level(),
+            Some(IntegrityLevel::SECURITY_MANDATORY_LOW_RID)
+        );
+
+        // Only the first label counts
+        let high_first: LocalBox<SecurityDescriptor> = "S:(ML;;NW;;;HI)(ML;;NW;;;LW)".parse()?;
+        assert_eq!(
+            high_first.integrity_level(),
+            Some(IntegrityLevel::SECURITY_MANDATORY_HIGH_RID)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn defaulted_components() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "O:SYG:BAD:(A;;FA;;;WD)S:(AU;FA;FA;;;WD)".parse()?;
+
+        assert!(!sd.owner_defaulted());
+        assert!(!sd.group_defaulted());
+        assert!(!sd.dacl_defaulted());
+        assert!(!sd.sacl_defaulted());
+
+        // An owner set by a default mechanism is reported as such
+        let owner: LocalBox<Sid> = "BA".parse()?;
+        let mut absolute = wrappers::MakeAbsoluteSD(&sd)?;
+        unsafe { wrappers::SetSecurityDescriptorOwner(&mut absolute, Some(&owner), true)? };
+        let defaulted = wrappers::MakeSelfRelativeSD(&absolute)?;
+
+        assert!(defaulted.owner_defaulted());
+        assert!(defaulted.control().contains(SdControl::OwnerDefaulted));
+        assert!(!defaulted.group_defaulted());
+        assert!(!defaulted.dacl_defaulted());
+        assert!(!defaulted.sacl_defaulted());
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_owner_keeps_dacl() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "O:SYG:SYD:P(A;;FA;;;WD)(D;;FW;;;BG)".parse()?;
+        let new_owner: LocalBox<Sid> = "S-1-5-21-1-2-3-1001".parse()?;
+
+        let sd2 = sd.with_owner(&new_owner)?;
+
+        assert_eq!(sd2.owner(), Some(new_owner.deref()));
+        assert_eq!(sd2.group(), sd.group());
+        assert_eq!(
+            wrappers::ConvertSecurityDescriptorToStringSecurityDescriptor(
+                &sd2,
+                SecurityInformation::Dacl
+            )?,
+            wrappers::ConvertSecurityDescriptorToStringSecurityDescriptor(
+                &sd,
+                SecurityInformation::Dacl
+            )?,
+        );
+        assert!(sd2.control().contains(SdControl::SelfRelative));
+
+        // The original is untouched
+        assert_eq!(
+            sd.as_sddl()?,
+            OsStr::new("O:SYG:SYD:P(A;;FA;;;WD)(D;;FW;;;BG)")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_components_replace_only_their_part() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "O:SYG:SYD:(A;;FA;;;WD)S:(AU;SA;FA;;;WD)".parse()?;
+        let other: LocalBox<SecurityDescriptor> =
+            "O:BAG:BAD:(A;;FR;;;BU)S:(AU;FA;FR;;;BU)".parse()?;
+
+        assert_eq!(
+            sd.with_group(other.group().unwrap())?.as_sddl()?,
+            OsStr::new("O:SYG:BAD:(A;;FA;;;WD)S:(AU;SA;FA;;;WD)")
+        );
+        assert_eq!(
+            sd.with_dacl(other.dacl().unwrap())?.as_sddl()?,
+            OsStr::new("O:SYG:SYD:(A;;FR;;;BU)S:(AU;SA;FA;;;WD)")
+        );
+        assert_eq!(
+            sd.with_sacl(other.sacl().unwrap())?.as_sddl()?,
+            OsStr::new("O:SYG:SYD:(A;;FA;;;WD)S:(AU;FA;FR;;;BU)")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn access_check_by_type_single_node() -> io::Result<()> {
+        use crate::Guid;
+
+        let sd: LocalBox<SecurityDescriptor> = "O:SYG:SYD:(A;;FR;;;WD)".parse()?;
+        let token = Handle::impersonation_token();
+        let object_types = [ObjectTypeListEntry {
+            level: crate::ffi::um::winnt::ACCESS_OBJECT_GUID,
+            object_type: "c434c045-9b91-4504-a2a0-aea9e781ec69".parse::<Guid>()?,
+        }];
+        let mapping = GenericMapping::file();
+
+        let granted =
+            sd.access_check_by_type(&token, &object_types, AccessRights::GenericRead, &mapping)?;
+        assert_eq!(granted, vec![AccessRights::FileGenericRead]);
+
+        let denied =
+            sd.access_check_by_type(&token, &object_types, AccessRights::Delete, &mapping)?;
+        assert_eq!(denied, vec![AccessRights::empty()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rm_control_round_trip() -> io::Result<()> {
+        let mut sd: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse()?;
+
+        assert_eq!(sd.rm_control(), None);
+        assert!(!sd.control().contains(SdControl::RmControlValid));
+
+        for value in [0x00, 0x42, 0xff].iter() {
+            sd.set_rm_control(Some(*value));
+            assert_eq!(sd.rm_control(), Some(*value));
+            assert!(sd.control().contains(SdControl::RmControlValid));
+        }
+
+        // The byte survives conversion to absolute format and back
+        let copy = sd.with_owner(sd.owner().unwrap())?;
+        assert_eq!(copy.rm_control(), Some(0xff));
+
+        sd.set_rm_control(None);
+        assert_eq!(sd.rm_control(), None);
+        assert!(!sd.control().contains(SdControl::RmControlValid));
+
+        // Clearing leaves the rest of the descriptor alone
+        assert_eq!(sd.as_sddl()?, OsStr::new("O:SYD:(A;;FA;;;WD)"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn control_flags_round_trip() -> io::Result<()> {
+        let dacl_flags = [
+            ("", SdControl::empty()),
+            ("P", SdControl::DaclProtected),
+            ("AI", SdControl::DaclAutoInherited),
+            ("AR", SdControl::DaclAutoInheritReq),
+            (
+                "PAI",
+                SdControl::DaclProtected | SdControl::DaclAutoInherited,
+            ),
+        ];
+        let sacl_flags = [
+            ("", SdControl::empty()),
+            ("P", SdControl::SaclProtected),
+            ("AI", SdControl::SaclAutoInherited),
+            ("AR", SdControl::SaclAutoInheritReq),
+            (
+                "PAI",
+                SdControl::SaclProtected | SdControl::SaclAutoInherited,
+            ),
+        ];
+        let system: LocalBox<Sid> = "SY".parse()?;
+
+        for (dacl_sddl, dacl_control) in dacl_flags.iter() {
+            for (sacl_sddl, sacl_control) in sacl_flags.iter() {
+                for aces in [("", ""), ("(A;;FA;;;WD)", "(AU;FA;FA;;;WD)")].iter() {
+                    let sddl =
+                        format!("O:BAG:BAD:{}{}S:{}{}", dacl_sddl, aces.0, sacl_sddl, aces.1);
+                    let sd: LocalBox<SecurityDescriptor> = sddl.parse()?;
+
+                    assert!(sd.control().contains(*dacl_control | *sacl_control));
+                    assert_eq!(sd.as_sddl()?, OsStr::new(&sddl));
+
+                    // Going through the absolute format keeps the flags too
+                    let copy = sd.with_owner(&system)?;
+                    assert_eq!(
+                        copy.as_sddl()?,
+                        OsStr::new(&sddl.replacen("O:BA", "O:SY", 1))
+                    );
+                    assert_eq!(
+                        copy.control() - SdControl::OwnerDefaulted,
+                        sd.control() - SdControl::OwnerDefaulted
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sddl_revision() -> io::Result<()> {
+        assert_eq!(SddlRevision::default(), SddlRevision::SDDL_REVISION_1);
+        assert_eq!(SddlRevision::SDDL_REVISION_1 as u8, 1);
+
+        for (sddl, _, _) in sddl_test_cases() {
+            let sd: LocalBox<SecurityDescriptor> = sddl.parse()?;
+
+            let default = sd.as_sddl()?;
+            let explicit = sd.as_sddl_revision(SddlRevision::SDDL_REVISION_1)?;
+
+            assert_eq!(default, OsStr::new(&sddl));
+            assert_eq!(explicit, default);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_redacted() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> =
+            "O:S-1-5-21-1-2-3-1001G:S-1-5-21-7-8-9D:(A;;FA;;;SY)\
+             (A;;FR;;;S-1-5-21-1-2-3-1002)(A;;FR;;;BA)S:(AU;FA;FA;;;S-1-5-21-4-5-6-500)"
+                .parse()?;
+
+        let debug = format!("{:?}", sd.debug_redacted());
+
+        assert!(debug.contains("S-1-5-21-1-2-3-****"), "{}", debug);
+        assert!(debug.contains("S-1-5-21-4-5-6-****"), "{}", debug);
+        assert!(debug.contains("S-1-5-18"), "{}", debug);
+        assert!(debug.contains("S-1-5-32-544"), "{}", debug);
+        // A domain SID has no RID to hide
+        assert!(debug.contains("S-1-5-21-7-8-9"), "{}", debug);
+        assert!(!debug.contains("1001"), "{}", debug);
+        assert!(!debug.contains("1002"), "{}", debug);
+        assert!(!debug.contains("-500"), "{}", debug);
+
+        // The shape of the descriptor is still visible
+        assert_eq!(debug.matches("ACCESS_ALLOWED_ACE_TYPE").count(), 3);
+        assert_eq!(debug.matches("SYSTEM_AUDIT_ACE_TYPE").count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn of_file_owner() -> io::Result<()> {
+        use crate::ffi::um::winnt::WinBuiltinAdministratorsSid;
+        use crate::utilities::current_process_sid;
+
+        let file = tempfile::NamedTempFile::new()?;
+        let sd = SecurityDescriptor::of_file(file.path())?;
+
+        let owner = sd.owner().expect("File has no owner");
+        let user = current_process_sid()?;
+        let admins = Sid::well_known_sid(WinBuiltinAdministratorsSid)?;
+
+        // Elevated processes usually default to Administrators as the owner
+        assert!(owner == &*user || owner == &*admins, "{:?}", owner);
+        assert!(sd.group().is_some());
+        assert!(sd.dacl().is_some());
+        assert!(sd.sacl().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn of_file_errors() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let err = SecurityDescriptor::of_file(&dir.path().join("missing")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+        let err =
+            SecurityDescriptor::of_file(&dir.path().join("missing").join("file")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+        Ok(())
+    }
+
+    #[test]
+    fn of_file_access_denied() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file");
+        std::fs::write(&path, b"")?;
+
+        // An OWNER RIGHTS entry replaces the rights the owner gets
+        // implicitly, so this DACL grants nothing to anyone
+        let sd: LocalBox<SecurityDescriptor> = "D:P(A;;;;;OW)".parse()?;
+        wrappers::SetNamedSecurityInfo(
+            &path,
+            SeObjectType::SE_FILE_OBJECT,
+            SecurityInformation::Dacl | SecurityInformation::ProtectedDacl,
+            None,
+            None,
+            sd.dacl(),
+            None,
+        )?;
+
+        let err = SecurityDescriptor::of_file(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+        Ok(())
+    }
+
+    #[test]
+    fn of_file_with_sacl_keeps_thread_token() -> io::Result<()> {
+        let file = tempfile::NamedTempFile::new()?;
+
+        // Not impersonating before or after
+        let _ = SecurityDescriptor::of_file_with_sacl(file.path());
+        let err = wrappers::OpenThreadToken(TOKEN_QUERY).unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(ERROR_NO_TOKEN as i32));
+
+        // Impersonating the same token before and after
+        let token = Handle::impersonation_token();
+        wrappers::SetThreadToken(Some(&token))?;
+        let _ = SecurityDescriptor::of_file_with_sacl(file.path());
+        let after = wrappers::OpenThreadToken(TOKEN_QUERY);
+        wrappers::SetThreadToken(None)?;
+
+        assert!(after.is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn of_file_with_sacl() -> io::Result<()> {
+        let file = tempfile::NamedTempFile::new()?;
+
+        match SecurityDescriptor::of_file_with_sacl(file.path()) {
+            Ok(sd) => assert!(sd.owner().is_some()),
+            // Not running elevated
+            Err(e) => assert_eq!(e.raw_os_error(), Some(ERROR_PRIVILEGE_NOT_HELD as i32)),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn null_and_empty_dacls() -> io::Result<()> {
+        let empty = SecurityDescriptor::empty()?;
+        assert!(wrappers::IsValidSecurityDescriptor(&empty));
+        assert!(!empty.control().contains(SdControl::SelfRelative));
+        assert!(!empty.control().contains(SdControl::DaclPresent));
+        assert!(!empty.grants_everyone());
+        assert_eq!(empty.owner(), None);
+        assert_eq!(empty.group(), None);
+        assert!(empty.sacl().is_none());
+
+        let allow_all = SecurityDescriptor::allow_all()?;
+        assert!(wrappers::IsValidSecurityDescriptor(&allow_all));
+        assert!(allow_all.control().contains(SdControl::DaclPresent));
+        assert!(allow_all.grants_everyone());
+        assert!(allow_all.dacl().is_none());
+
+        let deny_all = SecurityDescriptor::deny_all()?;
+        assert!(wrappers::IsValidSecurityDescriptor(&deny_all));
+        assert!(deny_all.control().contains(SdControl::DaclPresent));
+        assert!(!deny_all.grants_everyone());
+        assert_eq!(deny_all.dacl().map(|dacl| dacl.len()), Some(0));
+
+        // Parsed null DACLs behave the same way
+        let parsed: LocalBox<SecurityDescriptor> = "D:NO_ACCESS_CONTROL".parse()?;
+        assert!(parsed.grants_everyone());
+        assert_eq!(parsed.as_sddl()?, allow_all.as_sddl()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalize() -> io::Result<()> {
+        let cases = [
+            (
+                "O:SYG:BAD:(A;;FR;;;WD)(A;;FA;;;SY)(D;;FW;;;BG)S:(AU;FA;FA;;;WD)(AU;SA;FR;;;BU)",
+                "O:SYG:BAD:(D;;FW;;;BG)(A;;FA;;;SY)(A;;FR;;;WD)S:(AU;SA;FR;;;BU)(AU;FA;FA;;;WD)",
+            ),
+            // Inherited ACEs stay after explicit ones, and are sorted the same way
+            (
+                "D:AI(A;ID;FR;;;WD)(A;;FA;;;SY)(D;ID;FW;;;BG)(D;;FA;;;AN)",
+                "D:AI(D;;FA;;;AN)(A;;FA;;;SY)(D;ID;FW;;;BG)(A;ID;FR;;;WD)",
+            ),
+            // Duplicates are dropped
+            (
+                "D:(A;;FA;;;SY)(A;;FR;;;WD)(A;;FA;;;SY)",
+                "D:(A;;FR;;;WD)(A;;FA;;;SY)",
+            ),
+        ];
+
+        for (lhs, rhs) in cases.iter() {
+            let lhs: LocalBox<SecurityDescriptor> = lhs.parse()?;
+            let rhs: LocalBox<SecurityDescriptor> = rhs.parse()?;
+
+            let lhs = lhs.normalize()?;
+            let rhs = rhs.normalize()?;
+
+            assert!(wrappers::IsValidSecurityDescriptor(&lhs));
+            assert_eq!(lhs.as_bytes(), rhs.as_bytes());
+            assert_eq!(lhs.as_sddl()?, rhs.as_sddl()?);
+            assert!(lhs.as_bytes().is_some());
+        }
+
+        // Deny ACEs come first among the explicit and the inherited ACEs
+        let sd: LocalBox<SecurityDescriptor> =
+            "D:AI(A;ID;FR;;;WD)(A;;FR;;;WD)(D;ID;FW;;;BG)(D;;FW;;;BG)".parse()?;
+        let dacl_types: Vec<_> = sd
+            .normalize()?
+            .dacl()
+            .unwrap()
+            .aces()
+            .map(|ace| {
+                (
+                    ace.ace_type(),
+                    ace.flags().contains(crate::constants::AceFlags::Inherited),
+                )
+            })
+            .collect();
+        assert_eq!(
+            dacl_types,
+            [
+                (crate::constants::AceType::ACCESS_DENIED_ACE_TYPE, false),
+                (crate::constants::AceType::ACCESS_ALLOWED_ACE_TYPE, false),
+                (crate::constants::AceType::ACCESS_DENIED_ACE_TYPE, true),
+                (crate::constants::AceType::ACCESS_ALLOWED_ACE_TYPE, true),
+            ]
+        );
+
+        // Null and missing DACLs are left alone
+        let null: LocalBox<SecurityDescriptor> = "O:SYD:NO_ACCESS_CONTROL".parse()?;
+        assert!(null.normalize()?.grants_everyone());
+        let missing: LocalBox<SecurityDescriptor> = "O:SY".parse()?;
+        assert_eq!(missing.normalize()?.as_sddl()?, "O:SY");
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_bytes() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> =
+            "O:SYG:BAD:(A;;FA;;;WD)(D;;FW;;;BG)S:(AU;FA;FA;;;WD)".parse()?;
+        let bytes = sd.as_bytes().unwrap().to_vec();
+
+        let copy = SecurityDescriptor::from_bytes(&bytes)?;
+        assert_eq!(copy.as_sddl()?, sd.as_sddl()?);
+        assert_eq!(copy.as_bytes(), Some(&bytes[..]));
+
+        // Every truncation is caught before Windows reads past the end
+        for len in 0..bytes.len() {
+            let err = SecurityDescriptor::from_bytes(&bytes[..len]).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData, "length {}", len);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_bytes_rejects_huge_offsets() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "O:SYG:BAD:(A;;FA;;;WD)".parse()?;
+        let bytes = sd.as_bytes().unwrap().to_vec();
+
+        // Owner, group, SACL and DACL offsets
+        for at in [4, 8, 12, 16] {
+            let mut bytes = bytes.clone();
+            bytes[at..at + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+
+            let err = SecurityDescriptor::from_bytes(&bytes).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData, "offset at {}", at);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_bytes_rejects_absolute() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse()?;
+        let absolute = wrappers::MakeAbsoluteSD(&sd)?;
+        assert!(!absolute.control().contains(SdControl::SelfRelative));
+
+        // The raw header of an absolute descriptor holds pointers, not offsets
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                &*absolute as *const _ as *const u8,
+                mem::size_of::<crate::ffi::um::winnt::SECURITY_DESCRIPTOR>(),
+            )
+        };
+
+        let err = SecurityDescriptor::from_bytes(bytes).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("absolute"), "{}", err);
+
+        Ok(())
+    }
 }
@@ -1,8 +1,44 @@
-use crate::{wrappers, LocalBox};
+use crate::constants::SidNameUse;
+use crate::ffi::um::winnt::{SECURITY_BUILTIN_DOMAIN_RID, SECURITY_NT_NON_UNIQUE};
+use crate::{utilities, wrappers, LocalBox};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ffi::OsString;
 use std::fmt;
 use std::hash::Hash;
 use std::io;
 use std::str::FromStr;
+use std::sync::OnceLock;
+
+const NT_AUTHORITY: [u8; 6] = [0, 0, 0, 0, 0, 5];
+
+/// SDDL SID aliases that refer to the same SID on every machine
+///
+/// Aliases relative to the local machine or domain (such as `LA` or `DA`) are
+/// left out, since resolving them can require a domain lookup.
+const FIXED_ALIASES: &[&str] = &[
+    "AA", "AC", "AN", "AO", "AS", "AU", "BA", "BG", "BO", "BU", "CD", "CG", "CO", "CY", "ED", "ER",
+    "ES", "HA", "HI", "IS", "IU", "LS", "LU", "LW", "ME", "MP", "MU", "NO", "NS", "NU", "OW", "PO",
+    "PS", "PU", "RA", "RC", "RD", "RE", "RM", "RU", "SI", "SO", "SS", "SU", "SY", "WD", "WR",
+];
+
+/// Look up a fixed SDDL SID alias in the cache
+///
+/// The cache is filled the first time it is used. Aliases that Windows fails
+/// to resolve are not cached, so they fall back to the uncached path.
+fn cached_alias(alias: &str) -> Option<&'static Sid> {
+    static CACHE: OnceLock<HashMap<&'static str, LocalBox<Sid>>> = OnceLock::new();
+
+    CACHE
+        .get_or_init(|| {
+            FIXED_ALIASES
+                .iter()
+                .filter_map(|&alias| Some((alias, wrappers::ConvertStringSidToSid(alias).ok()?)))
+                .collect()
+        })
+        .get(alias)
+        .map(|sid| &**sid)
+}
 
 /// A SID (Security Identifier) that can be used with Windows API calls.
 #[repr(C)]
@@ -115,6 +151,206 @@ impl Sid {
         vec
     }
 
+    /// Split the SID into its domain SID and its RID
+    ///
+    /// The RID (relative identifier) is the last sub-authority. The domain SID
+    /// is the same SID with the RID removed. Returns `None` if the SID has no
+    /// sub-authorities.
+    ///
+    /// ```
+    /// use windows_permissions::{Sid, LocalBox};
+    ///
+    /// let sid: LocalBox<Sid> = "S-1-5-21-1-2-3-1001".parse().unwrap();
+    /// let (domain, rid) = sid.split_rid().unwrap();
+    ///
+    /// assert_eq!(domain.to_string(), "S-1-5-21-1-2-3");
+    /// assert_eq!(rid, 1001);
+    /// ```
+    pub fn split_rid(&self) -> Option<(LocalBox<Sid>, u32)> {
+        let mut sub_auths = self.sub_authorities();
+        let rid = sub_auths.pop()?;
+
+        let domain = wrappers::InitializeSid(*self.id_authority(), &sub_auths)
+            .expect("Failed to build the domain SID of a valid SID");
+
+        Some((domain, rid))
+    }
+
+    /// Get the domain SID of an account SID
+    ///
+    /// This uses [`wrappers::GetWindowsAccountDomainSid`], so unlike
+    /// [`split_rid`](Self::split_rid) it only succeeds for SIDs that really
+    /// belong to a domain or local machine account. Other SIDs fail with
+    /// `ERROR_NON_ACCOUNT_SID`.
+    ///
+    /// ```
+    /// use windows_permissions::{Sid, LocalBox};
+    ///
+    /// let user: LocalBox<Sid> = "S-1-5-21-1-2-3-1001".parse().unwrap();
+    /// let system: LocalBox<Sid> = "SY".parse().unwrap();
+    ///
+    /// assert_eq!(user.windows_account_domain().unwrap().to_string(), "S-1-5-21-1-2-3");
+    /// assert!(system.windows_account_domain().is_err());
+    /// ```
+    pub fn windows_account_domain(&self) -> io::Result<LocalBox<Sid>> {
+        wrappers::GetWindowsAccountDomainSid(self)
+    }
+
+    /// Check whether the SID is in the NT Authority (`S-1-5-...`)
+    ///
+    /// ```
+    /// use windows_permissions::{Sid, LocalBox};
+    ///
+    /// assert!("S-1-5-18".parse::<LocalBox<Sid>>().unwrap().is_nt_authority());
+    /// assert!(!"S-1-1-0".parse::<LocalBox<Sid>>().unwrap().is_nt_authority());
+    /// ```
+    pub fn is_nt_authority(&self) -> bool {
+        self.id_authority() == &NT_AUTHORITY
+    }
+
+    /// Check whether the SID is a BUILTIN alias (`S-1-5-32-...`)
+    ///
+    /// ```
+    /// use windows_permissions::{Sid, LocalBox};
+    ///
+    /// assert!("BA".parse::<LocalBox<Sid>>().unwrap().is_builtin());
+    /// assert!(!"SY".parse::<LocalBox<Sid>>().unwrap().is_builtin());
+    /// ```
+    pub fn is_builtin(&self) -> bool {
+        self.is_nt_authority() && self.sub_authority(0) == Some(SECURITY_BUILTIN_DOMAIN_RID)
+    }
+
+    /// Check whether the SID is a domain or local machine account
+    ///
+    /// Account SIDs look like `S-1-5-21-X-Y-Z-RID`: the `21` sub-authority is
+    /// followed by three sub-authorities identifying the domain, then the RID.
+    /// This checks for the `S-1-5-21` prefix and at least 4 sub-authorities,
+    /// so the domain SID itself (without a RID) also matches. Local accounts
+    /// have the same shape as domain accounts.
+    ///
+    /// ```
+    /// use windows_permissions::{Sid, LocalBox};
+    ///
+    /// let user: LocalBox<Sid> = "S-1-5-21-1-2-3-1001".parse().unwrap();
+    /// let domain: LocalBox<Sid> = "S-1-5-21-1-2-3".parse().unwrap();
+    /// let short: LocalBox<Sid> = "S-1-5-21-1-2".parse().unwrap();
+    ///
+    /// assert!(user.is_domain_account());
+    /// assert!(domain.is_domain_account());
+    /// assert!(!short.is_domain_account());
+    /// ```
+    pub fn is_domain_account(&self) -> bool {
+        self.is_nt_authority()
+            && self.sub_authority(0) == Some(SECURITY_NT_NON_UNIQUE)
+            && self.sub_authority_count() >= 4
+    }
+
+    /// Check whether this is the user SID of the current process
+    ///
+    /// This only matches the user account itself. Use
+    /// [`is_in_current_token_groups`](Self::is_in_current_token_groups) to
+    /// also match groups the user is a member of.
+    ///
+    /// ```
+    /// use windows_permissions::{Sid, LocalBox};
+    /// use windows_permissions::utilities::current_process_sid;
+    ///
+    /// assert!(current_process_sid().unwrap().is_current_user().unwrap());
+    /// assert!(!"S-1-5-18-1".parse::<LocalBox<Sid>>().unwrap().is_current_user().unwrap());
+    /// ```
+    pub fn is_current_user(&self) -> io::Result<bool> {
+        let user = utilities::current_process_sid()?;
+        Ok(wrappers::EqualSid(self, &user))
+    }
+
+    /// Check whether the current thread's effective token includes this SID
+    ///
+    /// This is true for the user SID and for every enabled group SID in the
+    /// token, so it is the right check for "does an ACE for this SID apply to
+    /// me?". Deny-only groups do not count.
+    ///
+    /// ```
+    /// use windows_permissions::{Sid, LocalBox};
+    ///
+    /// let everyone: LocalBox<Sid> = "WD".parse().unwrap();
+    ///
+    /// assert!(everyone.is_in_current_token_groups().unwrap());
+    /// ```
+    pub fn is_in_current_token_groups(&self) -> io::Result<bool> {
+        wrappers::CheckTokenMembership(None, self)
+    }
+
+    /// Look up the account name and domain of this SID
+    ///
+    /// Returns (name, domain). This is equivalent to calling
+    /// [`wrappers::LookupAccountSid`], so the Null SID and the Anonymous
+    /// Logon SID always give the same result regardless of the machine.
+    ///
+    /// ```
+    /// use windows_permissions::{Sid, LocalBox};
+    ///
+    /// let anonymous: LocalBox<Sid> = "S-1-5-7".parse().unwrap();
+    /// let (name, domain) = anonymous.lookup_account().unwrap();
+    ///
+    /// assert_eq!(name, "ANONYMOUS LOGON");
+    /// assert_eq!(domain, "NT AUTHORITY");
+    /// ```
+    pub fn lookup_account(&self) -> io::Result<(OsString, OsString)> {
+        wrappers::LookupAccountSid(self)
+    }
+
+    /// Look up an account name and find its SID, domain, and account type
+    ///
+    /// The name is looked up with [`wrappers::LookupAccountName`] on
+    /// `system`, or on the local system if `system` is `None`. The account
+    /// type tells apart, for example, users from groups and aliases.
+    ///
+    /// ```
+    /// use windows_permissions::{LocalBox, Sid};
+    /// use windows_permissions::constants::SidNameUse;
+    ///
+    /// let account = Sid::resolve(r"NT AUTHORITY\SYSTEM", None).unwrap();
+    /// let system: LocalBox<Sid> = "SY".parse().unwrap();
+    ///
+    /// assert_eq!(account.sid, system);
+    /// assert_eq!(account.use_type, SidNameUse::SidTypeWellKnownGroup);
+    /// ```
+    pub fn resolve(account: &str, system: Option<&str>) -> io::Result<ResolvedAccount> {
+        let (sid, domain, use_type) = wrappers::LookupAccountName(system, account)?;
+
+        Ok(ResolvedAccount {
+            sid: wrappers::CopySid(&sid)?,
+            domain,
+            use_type,
+        })
+    }
+
+    /// Derive the SIDs of a named capability, such as `"internetClient"`
+    ///
+    /// Capability SIDs (`S-1-15-3-...`) are granted to AppContainer
+    /// processes. The group SID (`S-1-5-32-...`) is used for the same
+    /// capability outside of AppContainers.
+    ///
+    /// ```
+    /// use windows_permissions::Sid;
+    ///
+    /// let capability = Sid::capability("internetClient").unwrap();
+    ///
+    /// assert!(capability.sid.to_string().starts_with("S-1-15-3-"));
+    /// assert!(capability.group_sid.to_string().starts_with("S-1-5-32-"));
+    /// ```
+    pub fn capability(name: &str) -> io::Result<CapabilitySid> {
+        let (mut group_sids, mut sids) = wrappers::DeriveCapabilitySidsFromName(name)?;
+
+        match (group_sids.pop(), sids.pop()) {
+            (Some(group_sid), Some(sid)) => Ok(CapabilitySid { sid, group_sid }),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "DeriveCapabilitySidsFromName returned no SIDs",
+            )),
+        }
+    }
+
     /// Get the numeric value of an ID authority
     ///
     /// ```
@@ -135,6 +371,83 @@ impl Sid {
     }
 }
 
+/// The SIDs derived from a capability name by [`Sid::capability`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CapabilitySid {
+    /// The capability SID, used in AppContainer tokens (`S-1-15-3-...`)
+    pub sid: LocalBox<Sid>,
+
+    /// The capability group SID (`S-1-5-32-...`)
+    pub group_sid: LocalBox<Sid>,
+}
+
+/// Shared copies of common well-known SIDs.
+///
+/// Each SID is created once, the first time any of them is used, and then
+/// borrowed for the life of the program. This avoids an allocation for every
+/// ACL entry when building many ACLs.
+///
+/// ```
+/// use windows_permissions::{LocalBox, Sid};
+/// use windows_permissions::structures::WellKnownSids;
+///
+/// let everyone: LocalBox<Sid> = "S-1-1-0".parse().unwrap();
+///
+/// assert_eq!(WellKnownSids::everyone(), &*everyone);
+/// ```
+#[derive(Debug)]
+pub struct WellKnownSids {
+    _private: (),
+}
+
+macro_rules! well_known_sids {
+    ( $( $(#[$meta:meta])* $name:ident => $alias:expr ),* $(,)? ) => {
+        impl WellKnownSids {
+            $(
+                $(#[$meta])*
+                pub fn $name() -> &'static Sid {
+                    cached_alias($alias)
+                        .expect(concat!("Failed to create well-known SID ", $alias))
+                }
+            )*
+        }
+    };
+}
+
+well_known_sids! {
+    /// Everyone (`S-1-1-0`, SDDL `WD`)
+    everyone => "WD",
+    /// Creator Owner (`S-1-3-0`, SDDL `CO`)
+    creator_owner => "CO",
+    /// Local System (`S-1-5-18`, SDDL `SY`)
+    local_system => "SY",
+    /// Local Service (`S-1-5-19`, SDDL `LS`)
+    local_service => "LS",
+    /// Network Service (`S-1-5-20`, SDDL `NS`)
+    network_service => "NS",
+    /// Authenticated Users (`S-1-5-11`, SDDL `AU`)
+    authenticated_users => "AU",
+    /// BUILTIN\Administrators (`S-1-5-32-544`, SDDL `BA`)
+    builtin_administrators => "BA",
+    /// BUILTIN\Users (`S-1-5-32-545`, SDDL `BU`)
+    builtin_users => "BU",
+    /// BUILTIN\Guests (`S-1-5-32-546`, SDDL `BG`)
+    builtin_guests => "BG",
+}
+
+/// An account found by [`Sid::resolve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedAccount {
+    /// The SID of the account
+    pub sid: LocalBox<Sid>,
+
+    /// The domain the account was found in
+    pub domain: OsString,
+
+    /// The type of account, such as a user, group, or alias
+    pub use_type: SidNameUse,
+}
+
 #[cfg(test)]
 impl Sid {
     /// Return an iterator that yields a whole bunch of SIDs you can test
@@ -199,6 +512,30 @@ impl fmt::Display for Sid {
     }
 }
 
+/// Sorts SIDs for presentation
+///
+/// SIDs are compared by identifier authority, then sub-authority by
+/// sub-authority, so sorted SIDs appear in the natural numeric order of their
+/// string forms (`S-1-1-0` < `S-1-5-18` < `S-1-5-32-544`). A SID sorts before
+/// any longer SID that starts with it.
+///
+/// This ordering is only for stable output. It has no meaning for security:
+/// it says nothing about privilege, and Windows does not use it.
+impl Ord for Sid {
+    fn cmp(&self, other: &Sid) -> Ordering {
+        // The authority is stored big-endian, so byte order is numeric order
+        self.id_authority()
+            .cmp(other.id_authority())
+            .then_with(|| self.sub_authorities().cmp(&other.sub_authorities()))
+    }
+}
+
+impl PartialOrd for Sid {
+    fn partial_cmp(&self, other: &Sid) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Hash for Sid {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.id_authority().hash(state);
@@ -214,7 +551,10 @@ impl FromStr for LocalBox<Sid> {
     type Err = io::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        wrappers::ConvertStringSidToSid(s)
+        match cached_alias(s) {
+            Some(sid) => wrappers::CopySid(sid),
+            None => wrappers::ConvertStringSidToSid(s),
+        }
     }
 }
 
@@ -249,4 +589,217 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn cached_aliases() -> io::Result<()> {
+        let first: LocalBox<Sid> = "WD".parse()?;
+        let second: LocalBox<Sid> = "WD".parse()?;
+
+        assert_eq!(first, second);
+        assert_eq!(first.to_string(), "S-1-1-0");
+
+        // Every fixed alias resolves the same way with and without the cache
+        for alias in FIXED_ALIASES {
+            if let Some(cached) = cached_alias(alias) {
+                assert_eq!(
+                    cached,
+                    &*wrappers::ConvertStringSidToSid(alias)?,
+                    "{}",
+                    alias
+                );
+            }
+        }
+
+        // Everything else still goes through Windows
+        assert!(cached_alias("S-1-1-0").is_none());
+        assert!(cached_alias("DA").is_none());
+        assert!("ZZ".parse::<LocalBox<Sid>>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn well_known_sids() -> io::Result<()> {
+        use crate::ffi::um::winnt::{WinBuiltinAdministratorsSid, WinLocalSystemSid, WinWorldSid};
+
+        // The same SID is handed out every time
+        assert!(std::ptr::eq(
+            WellKnownSids::everyone(),
+            WellKnownSids::everyone()
+        ));
+
+        assert_eq!(
+            WellKnownSids::everyone(),
+            &*Sid::well_known_sid(WinWorldSid)?
+        );
+        assert_eq!(
+            WellKnownSids::local_system(),
+            &*Sid::well_known_sid(WinLocalSystemSid)?
+        );
+        assert_eq!(
+            WellKnownSids::builtin_administrators(),
+            &*Sid::well_known_sid(WinBuiltinAdministratorsSid)?
+        );
+        assert_eq!(WellKnownSids::builtin_guests().to_string(), "S-1-5-32-546");
+
+        Ok(())
+    }
+
+    #[test]
+    fn current_user() -> io::Result<()> {
+        let user = utilities::current_process_sid()?;
+        let stranger: LocalBox<Sid> = "S-1-5-21-3623811015-3361044348-30300820-1013".parse()?;
+
+        assert!(user.is_current_user()?);
+        assert!(!stranger.is_current_user()?);
+
+        assert!(user.is_in_current_token_groups()?);
+        assert!(!stranger.is_in_current_token_groups()?);
+
+        // Everyone is a group, not the user
+        let everyone: LocalBox<Sid> = "WD".parse()?;
+        assert!(!everyone.is_current_user()?);
+        assert!(everyone.is_in_current_token_groups()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ordering() -> io::Result<()> {
+        let mut sids: Vec<LocalBox<Sid>> = ["S-1-5-32-544", "S-1-1-0", "S-1-5-18"]
+            .iter()
+            .map(|sid| sid.parse())
+            .collect::<io::Result<_>>()?;
+
+        sids.sort();
+
+        let sorted: Vec<String> = sids.iter().map(|sid| sid.to_string()).collect();
+        assert_eq!(sorted, ["S-1-1-0", "S-1-5-18", "S-1-5-32-544"]);
+
+        // Sub-authorities compare numerically, and a prefix sorts first
+        let cases = [
+            ("S-1-5-21-1-2-3", "S-1-5-21-1-2-3-500"),
+            ("S-1-5-21-1-2-3-500", "S-1-5-21-1-2-3-1000"),
+            ("S-1-5-32-544", "S-1-15-2-1"),
+            ("S-1-16-8192", "S-1-0x10000000000-1"),
+        ];
+
+        for (lower, higher) in cases.iter() {
+            let lower: LocalBox<Sid> = lower.parse()?;
+            let higher: LocalBox<Sid> = higher.parse()?;
+
+            assert!(lower < higher, "{} < {}", lower, higher);
+            assert!(higher > lower, "{} > {}", higher, lower);
+        }
+
+        let a: LocalBox<Sid> = "S-1-5-18".parse()?;
+        let b: LocalBox<Sid> = "SY".parse()?;
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_rid() {
+        let sid: LocalBox<Sid> = "S-1-5-21-1-2-3-1001".parse().unwrap();
+        let domain: LocalBox<Sid> = "S-1-5-21-1-2-3".parse().unwrap();
+
+        assert_eq!(sid.split_rid(), Some((domain, 1001)));
+
+        let single: LocalBox<Sid> = "S-1-5-18".parse().unwrap();
+        let (authority_only, rid) = single.split_rid().unwrap();
+        assert_eq!(authority_only.sub_authority_count(), 0);
+        assert_eq!(authority_only.id_authority(), &[0, 0, 0, 0, 0, 5]);
+        assert_eq!(rid, 18);
+
+        let empty = wrappers::InitializeSid([0, 0, 0, 0, 0, 5], &[]).unwrap();
+        assert_eq!(empty.split_rid(), None);
+    }
+
+    #[test]
+    fn windows_account_domain() {
+        use crate::ffi::shared::winerror::ERROR_NON_ACCOUNT_SID;
+
+        let user: LocalBox<Sid> = "S-1-5-21-1-2-3-1001".parse().unwrap();
+        let domain: LocalBox<Sid> = "S-1-5-21-1-2-3".parse().unwrap();
+        assert_eq!(user.windows_account_domain().unwrap(), domain);
+
+        let system: LocalBox<Sid> = "SY".parse().unwrap();
+        assert_eq!(
+            system
+                .windows_account_domain()
+                .err()
+                .and_then(|e| e.raw_os_error()),
+            Some(ERROR_NON_ACCOUNT_SID as i32)
+        );
+    }
+
+    #[test]
+    fn resolve() -> io::Result<()> {
+        // Look the name up first, since BUILTIN names are localized
+        let administrators: LocalBox<Sid> = "BA".parse()?;
+        let (name, domain) = administrators.lookup_account()?;
+        let account = format!("{}\\{}", domain.to_string_lossy(), name.to_string_lossy());
+
+        let resolved = Sid::resolve(&account, None)?;
+        assert_eq!(resolved.sid, administrators);
+        assert_eq!(resolved.domain, domain);
+        assert_eq!(resolved.use_type, SidNameUse::SidTypeAlias);
+
+        let user = Sid::resolve(r"NO SUCH DOMAIN\no such user", None);
+        assert!(user.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn capability() {
+        let capability = Sid::capability("internetClient").unwrap();
+
+        assert_eq!(capability.sid.id_authority(), &[0, 0, 0, 0, 0, 15]);
+        assert_eq!(capability.sid.sub_authority(0), Some(3));
+        assert!(capability.sid.to_string().starts_with("S-1-15-3-"));
+
+        assert_eq!(capability.group_sid.id_authority(), &[0, 0, 0, 0, 0, 5]);
+        assert_eq!(capability.group_sid.sub_authority(0), Some(32));
+
+        // Deriving is deterministic
+        assert_eq!(Sid::capability("internetClient").unwrap(), capability);
+    }
+
+    #[test]
+    fn classification() {
+        // (SID, NT authority, BUILTIN, domain account)
+        let cases = [
+            // BUILTIN\Administrators, and the BUILTIN domain itself
+            ("S-1-5-32-544", true, true, false),
+            ("S-1-5-32", true, true, false),
+            // LocalSystem and the TrustedInstaller service
+            ("S-1-5-18", true, false, false),
+            (
+                "S-1-5-80-956008885-3418522649-1831038044-1853292631-2271478464",
+                true,
+                false,
+                false,
+            ),
+            // Domain accounts, a domain SID without a RID, and a SID too
+            // short to identify a domain
+            ("S-1-5-21-1-2-3-1001", true, false, true),
+            ("S-1-5-21-1-2-3-500-1", true, false, true),
+            ("S-1-5-21-1-2-3", true, false, true),
+            ("S-1-5-21-1-2", true, false, false),
+            // Outside the NT Authority
+            ("S-1-1-0", false, false, false),
+            ("S-1-16-12288", false, false, false),
+            ("S-1-3-21-1-2-3-1001", false, false, false),
+        ];
+
+        for (sid, nt_authority, builtin, domain_account) in cases.iter() {
+            let sid: LocalBox<Sid> = sid.parse().unwrap();
+
+            assert_eq!(sid.is_nt_authority(), *nt_authority, "{}", sid);
+            assert_eq!(sid.is_builtin(), *builtin, "{}", sid);
+            assert_eq!(sid.is_domain_account(), *domain_account, "{}", sid);
+        }
+    }
 }
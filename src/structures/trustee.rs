@@ -1,21 +1,23 @@
 use crate::constants::TrusteeForm;
+use crate::ffi::um::accctrl::TRUSTEE_W;
 use crate::utilities;
 use crate::wrappers;
-use crate::Sid;
-use std::ffi::OsStr;
+use crate::{LocalBox, Sid};
+use std::ffi::{c_void, OsStr};
 use std::fmt;
+use std::io;
 use std::marker::PhantomData;
 use std::ptr::NonNull;
-use winapi::ctypes::c_void;
-use winapi::um::accctrl::TRUSTEE_W;
 
 /// An entity that can be added to an ACL.
 ///
 /// Trustees can identify their subject (usually an account or a group) using a
-/// string or a `Sid`.
+/// string or a `Sid`. A trustee built from an `&OsStr` or `&str` owns its copy
+/// of the name, which is freed when the trustee is dropped.
 #[repr(C)]
 pub struct Trustee<'s> {
     inner: TRUSTEE_W,
+    name: Option<Box<[u16]>>,
     _phantom: PhantomData<TrusteeSubject<'s>>,
 }
 
@@ -41,14 +43,14 @@ pub enum TrusteeSubject<'s> {
 }
 
 impl<'s> Trustee<'s> {
-    /// Get a pointer to the underlying buffer
-    pub fn as_ptr(&self) -> *const TRUSTEE_W {
-        &self.inner
+    /// Get a pointer to the underlying `TRUSTEE_W` buffer
+    pub fn as_ptr(&self) -> *const c_void {
+        &self.inner as *const _ as *const _
     }
 
-    /// Get a mutable pointer to the underlying buffer
-    pub fn as_mut_ptr(&mut self) -> *mut TRUSTEE_W {
-        &mut self.inner
+    /// Get a mutable pointer to the underlying `TRUSTEE_W` buffer
+    pub fn as_mut_ptr(&mut self) -> *mut c_void {
+        &mut self.inner as *mut _ as *mut _
     }
 
     /// Allocate and zero-initialize space for a Trustee
@@ -60,10 +62,21 @@ impl<'s> Trustee<'s> {
     pub unsafe fn allocate() -> Self {
         Self {
             inner: std::mem::zeroed(),
+            name: None,
             _phantom: PhantomData,
         }
     }
 
+    /// Take ownership of the name buffer this trustee points to
+    ///
+    /// # Safety
+    ///
+    /// `name` must be the buffer the trustee's name pointer refers to.
+    pub(crate) unsafe fn own_name(&mut self, name: Box<[u16]>) {
+        debug_assert_eq!(self.inner.ptstrName as *const u16, name.as_ptr());
+        self.name = Some(name);
+    }
+
     /// Get the `TrusteeSubject` of a `Trustee`
     ///
     /// # Panics
@@ -72,7 +85,7 @@ impl<'s> Trustee<'s> {
     /// value. To get the value, use `wrappers::GetTrusteeForm` directly.
     ///
     /// Also panics if the pointer value is null.
-    pub fn get_subject(&self) -> TrusteeSubject<'s> {
+    pub fn get_subject(&self) -> TrusteeSubject<'_> {
         let form = wrappers::GetTrusteeForm(&self)
             .unwrap_or_else(|f| panic!("Trustee had unrecognized form: {:x}", f));
 
@@ -106,6 +119,38 @@ impl<'s> Trustee<'s> {
             TrusteeForm::TRUSTEE_BAD_FORM => TrusteeSubject::Bad,
         }
     }
+
+    /// Find the SID this trustee refers to
+    ///
+    /// A trustee holding a SID returns a copy of it. A trustee holding a name
+    /// looks it up with [`wrappers::LookupAccountName`] on `system`, or on
+    /// the local system if `system` is `None`, so a name that does not
+    /// resolve is reported here rather than by whatever uses the trustee
+    /// later. Other trustee forms fail with `InvalidInput`.
+    ///
+    /// ```
+    /// use std::ffi::{c_void, OsStr};
+    /// use windows_permissions::{LocalBox, Sid, Trustee};
+    ///
+    /// let trustee = Trustee::from(OsStr::new(r"NT AUTHORITY\SYSTEM"));
+    /// let system: LocalBox<Sid> = "S-1-5-18".parse().unwrap();
+    ///
+    /// assert_eq!(trustee.resolve_sid(None).unwrap(), system);
+    /// ```
+    pub fn resolve_sid(&self, system: Option<&str>) -> io::Result<LocalBox<Sid>> {
+        match self.get_subject() {
+            TrusteeSubject::Sid(sid) => wrappers::CopySid(sid),
+            TrusteeSubject::Name(name) => {
+                let (sid, _, _) =
+                    wrappers::LookupAccountName(system, utilities::os_from_buf(name))?;
+                wrappers::CopySid(&sid)
+            }
+            other => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Cannot resolve a SID from trustee subject {:?}", other),
+            )),
+        }
+    }
 }
 
 impl<'s> From<&'s Sid> for Trustee<'s> {
@@ -120,6 +165,31 @@ impl From<&OsStr> for Trustee<'static> {
     }
 }
 
+impl From<&str> for Trustee<'static> {
+    fn from(name: &str) -> Self {
+        wrappers::BuildTrusteeWithNameOsStr(OsStr::new(name))
+    }
+}
+
+/// Copies the trustee, which still borrows from the same subject
+///
+/// A trustee that owns its name gets its own copy of the name.
+impl<'s> From<&Trustee<'s>> for Trustee<'s> {
+    fn from(trustee: &Trustee<'s>) -> Self {
+        let mut copy = Self {
+            inner: trustee.inner,
+            name: trustee.name.clone(),
+            _phantom: PhantomData,
+        };
+
+        if let Some(name) = &copy.name {
+            copy.inner.ptstrName = name.as_ptr() as *mut _;
+        }
+
+        copy
+    }
+}
+
 impl<'s> fmt::Debug for Trustee<'s> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.debug_map().finish()
@@ -160,4 +230,50 @@ mod tests {
             assert_eq!(wrappers::GetTrusteeName(&trustee), **name);
         }
     }
+
+    #[test]
+    fn resolve_sid() -> io::Result<()> {
+        let system: LocalBox<Sid> = "S-1-5-18".parse()?;
+
+        let by_name = Trustee::from(OsStr::new(r"NT AUTHORITY\SYSTEM"));
+        assert_eq!(by_name.resolve_sid(None)?, system);
+
+        let by_sid = Trustee::from(&*system);
+        assert_eq!(by_sid.resolve_sid(None)?, system);
+
+        let unknown = Trustee::from(OsStr::new(r"NO SUCH DOMAIN\no such user"));
+        assert!(unknown.resolve_sid(None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_name_trustee_from_str() {
+        for name in TRUSTEE_NAMES {
+            let trustee: Trustee = (*name).into();
+
+            assert_eq!(wrappers::GetTrusteeName(&trustee), **name);
+        }
+    }
+
+    #[test]
+    fn copy_outlives_name_trustee() {
+        let trustee = Trustee::from("test_name");
+        let copy = Trustee::from(&trustee);
+        drop(trustee);
+
+        assert_eq!(wrappers::GetTrusteeName(&copy), "test_name");
+    }
+
+    #[test]
+    fn copy_trustee() {
+        let sid: LocalBox<Sid> = "SY".parse().unwrap();
+        let trustee: Trustee = (&*sid).into();
+        let copy = Trustee::from(&trustee);
+
+        match copy.get_subject() {
+            TrusteeSubject::Sid(s) => assert_eq!(s, &*sid),
+            _ => panic!("Expected to get back a TrusteeSubject::Sid"),
+        }
+    }
 }
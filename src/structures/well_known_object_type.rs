@@ -0,0 +1,324 @@
+use crate::Guid;
+use std::fmt;
+
+/// A common Active Directory schema object class or property set
+///
+/// These are the GUIDs that most often appear in the object type fields of
+/// object ACEs on directory objects. Use [`Guid::as_well_known_object`] to
+/// identify one.
+///
+/// Requires the `schema` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WellKnownObjectType {
+    /// The `user` object class
+    User,
+    /// The `group` object class
+    Group,
+    /// The `computer` object class
+    Computer,
+    /// The `organizationalUnit` object class
+    OrganizationalUnit,
+    /// The `contact` object class
+    Contact,
+    /// The `inetOrgPerson` object class
+    InetOrgPerson,
+    /// The `groupPolicyContainer` object class
+    GroupPolicyContainer,
+
+    /// The General Information property set
+    GeneralInformation,
+    /// The Account Restrictions property set
+    AccountRestrictions,
+    /// The Logon Information property set
+    LogonInformation,
+    /// The Group Membership property set
+    GroupMembership,
+    /// The Personal Information property set
+    PersonalInformation,
+    /// The Public Information property set
+    PublicInformation,
+    /// The Phone and Mail Options property set
+    EmailInformation,
+    /// The Web Information property set
+    WebInformation,
+    /// The Domain Password and Lockout Policies property set
+    DomainPassword,
+    /// The Remote Access Information property set
+    RasInformation,
+}
+
+const OBJECT_TYPES: &[(WellKnownObjectType, Guid)] = &[
+    (
+        WellKnownObjectType::User,
+        Guid::new(
+            0xbf967aba,
+            0x0de6,
+            0x11d0,
+            [0xa2, 0x85, 0x00, 0xaa, 0x00, 0x30, 0x49, 0xe2],
+        ),
+    ),
+    (
+        WellKnownObjectType::Group,
+        Guid::new(
+            0xbf967a9c,
+            0x0de6,
+            0x11d0,
+            [0xa2, 0x85, 0x00, 0xaa, 0x00, 0x30, 0x49, 0xe2],
+        ),
+    ),
+    (
+        WellKnownObjectType::Computer,
+        Guid::new(
+            0xbf967a86,
+            0x0de6,
+            0x11d0,
+            [0xa2, 0x85, 0x00, 0xaa, 0x00, 0x30, 0x49, 0xe2],
+        ),
+    ),
+    (
+        WellKnownObjectType::OrganizationalUnit,
+        Guid::new(
+            0xbf967aa5,
+            0x0de6,
+            0x11d0,
+            [0xa2, 0x85, 0x00, 0xaa, 0x00, 0x30, 0x49, 0xe2],
+        ),
+    ),
+    (
+        WellKnownObjectType::Contact,
+        Guid::new(
+            0x5cb41ed0,
+            0x0e4c,
+            0x11d0,
+            [0xa2, 0x86, 0x00, 0xaa, 0x00, 0x30, 0x49, 0xe2],
+        ),
+    ),
+    (
+        WellKnownObjectType::InetOrgPerson,
+        Guid::new(
+            0x4828cc14,
+            0x1437,
+            0x45bc,
+            [0x9b, 0x07, 0xad, 0x6f, 0x01, 0x5e, 0x5f, 0x28],
+        ),
+    ),
+    (
+        WellKnownObjectType::GroupPolicyContainer,
+        Guid::new(
+            0xf30e3bc2,
+            0x9ff0,
+            0x11d1,
+            [0xb6, 0x03, 0x00, 0x00, 0xf8, 0x03, 0x67, 0xc1],
+        ),
+    ),
+    (
+        WellKnownObjectType::GeneralInformation,
+        Guid::new(
+            0x59ba2f42,
+            0x79a2,
+            0x11d0,
+            [0x90, 0x20, 0x00, 0xc0, 0x4f, 0xc2, 0xd3, 0xcf],
+        ),
+    ),
+    (
+        WellKnownObjectType::AccountRestrictions,
+        Guid::new(
+            0x4c164200,
+            0x20c0,
+            0x11d0,
+            [0xa7, 0x68, 0x00, 0xaa, 0x00, 0x6e, 0x05, 0x29],
+        ),
+    ),
+    (
+        WellKnownObjectType::LogonInformation,
+        Guid::new(
+            0x5f202010,
+            0x79a5,
+            0x11d0,
+            [0x90, 0x20, 0x00, 0xc0, 0x4f, 0xc2, 0xd4, 0xcf],
+        ),
+    ),
+    (
+        WellKnownObjectType::GroupMembership,
+        Guid::new(
+            0xbc0ac240,
+            0x79a9,
+            0x11d0,
+            [0x90, 0x20, 0x00, 0xc0, 0x4f, 0xc2, 0xd4, 0xcf],
+        ),
+    ),
+    (
+        WellKnownObjectType::PersonalInformation,
+        Guid::new(
+            0x77b5b886,
+            0x944a,
+            0x11d1,
+            [0xae, 0xbd, 0x00, 0x00, 0xf8, 0x03, 0x67, 0xc1],
+        ),
+    ),
+    (
+        WellKnownObjectType::PublicInformation,
+        Guid::new(
+            0xe48d0154,
+            0xbcf8,
+            0x11d1,
+            [0x87, 0x02, 0x00, 0xc0, 0x4f, 0xb9, 0x60, 0x50],
+        ),
+    ),
+    (
+        WellKnownObjectType::EmailInformation,
+        Guid::new(
+            0xe45795b2,
+            0x9455,
+            0x11d1,
+            [0xae, 0xbd, 0x00, 0x00, 0xf8, 0x03, 0x67, 0xc1],
+        ),
+    ),
+    (
+        WellKnownObjectType::WebInformation,
+        Guid::new(
+            0xe45795b3,
+            0x9455,
+            0x11d1,
+            [0xae, 0xbd, 0x00, 0x00, 0xf8, 0x03, 0x67, 0xc1],
+        ),
+    ),
+    (
+        WellKnownObjectType::DomainPassword,
+        Guid::new(
+            0xc7407360,
+            0x20bf,
+            0x11d0,
+            [0xa7, 0x68, 0x00, 0xaa, 0x00, 0x6e, 0x05, 0x29],
+        ),
+    ),
+    (
+        WellKnownObjectType::RasInformation,
+        Guid::new(
+            0x037088f8,
+            0x0ae1,
+            0x11d2,
+            [0xb4, 0x22, 0x00, 0xa0, 0xc9, 0x68, 0xf9, 0x39],
+        ),
+    ),
+];
+
+impl WellKnownObjectType {
+    /// Get the schema GUID for this object type
+    ///
+    /// For object classes, this is the class's `schemaIDGUID`. For property
+    /// sets, it is the `rightsGuid` of the property set's control access
+    /// right.
+    pub fn guid(self) -> Guid {
+        OBJECT_TYPES
+            .iter()
+            .find(|(object_type, _)| *object_type == self)
+            .map(|(_, guid)| *guid)
+            .expect("Every WellKnownObjectType has a GUID")
+    }
+
+    /// Get the schema name of this object type
+    ///
+    /// This is the LDAP display name for object classes and the common name
+    /// for property sets.
+    pub fn name(self) -> &'static str {
+        match self {
+            WellKnownObjectType::User => "user",
+            WellKnownObjectType::Group => "group",
+            WellKnownObjectType::Computer => "computer",
+            WellKnownObjectType::OrganizationalUnit => "organizationalUnit",
+            WellKnownObjectType::Contact => "contact",
+            WellKnownObjectType::InetOrgPerson => "inetOrgPerson",
+            WellKnownObjectType::GroupPolicyContainer => "groupPolicyContainer",
+            WellKnownObjectType::GeneralInformation => "General-Information",
+            WellKnownObjectType::AccountRestrictions => "User-Account-Restrictions",
+            WellKnownObjectType::LogonInformation => "User-Logon",
+            WellKnownObjectType::GroupMembership => "Membership",
+            WellKnownObjectType::PersonalInformation => "Personal-Information",
+            WellKnownObjectType::PublicInformation => "Public-Information",
+            WellKnownObjectType::EmailInformation => "Email-Information",
+            WellKnownObjectType::WebInformation => "Web-Information",
+            WellKnownObjectType::DomainPassword => "Domain-Password",
+            WellKnownObjectType::RasInformation => "RAS-Information",
+        }
+    }
+
+    /// Check whether this is a property set rather than an object class
+    pub fn is_property_set(self) -> bool {
+        !matches!(
+            self,
+            WellKnownObjectType::User
+                | WellKnownObjectType::Group
+                | WellKnownObjectType::Computer
+                | WellKnownObjectType::OrganizationalUnit
+                | WellKnownObjectType::Contact
+                | WellKnownObjectType::InetOrgPerson
+                | WellKnownObjectType::GroupPolicyContainer
+        )
+    }
+
+    pub(crate) fn from_guid(guid: &Guid) -> Option<Self> {
+        OBJECT_TYPES
+            .iter()
+            .find(|(_, known)| known == guid)
+            .map(|(object_type, _)| *object_type)
+    }
+}
+
+impl fmt::Display for WellKnownObjectType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known_guids() {
+        let cases = [
+            (
+                "bf967aba-0de6-11d0-a285-00aa003049e2",
+                WellKnownObjectType::User,
+            ),
+            (
+                "bf967a9c-0de6-11d0-a285-00aa003049e2",
+                WellKnownObjectType::Group,
+            ),
+            (
+                "bf967a86-0de6-11d0-a285-00aa003049e2",
+                WellKnownObjectType::Computer,
+            ),
+            (
+                "bc0ac240-79a9-11d0-9020-00c04fc2d4cf",
+                WellKnownObjectType::GroupMembership,
+            ),
+        ];
+
+        for (text, expected) in cases.iter() {
+            let guid: Guid = text.parse().unwrap();
+            assert_eq!(guid.as_well_known_object(), Some(*expected), "{}", text);
+            assert_eq!(expected.guid(), guid);
+        }
+    }
+
+    #[test]
+    fn unknown_guid() {
+        let guid: Guid = "c434c045-9b91-4504-a2a0-aea9e781ec69".parse().unwrap();
+        assert_eq!(guid.as_well_known_object(), None);
+    }
+
+    #[test]
+    fn round_trip_every_variant() {
+        for (object_type, guid) in OBJECT_TYPES {
+            assert_eq!(guid.as_well_known_object(), Some(*object_type));
+            assert_eq!(object_type.guid(), *guid);
+        }
+
+        assert!(!WellKnownObjectType::User.is_property_set());
+        assert!(WellKnownObjectType::GroupMembership.is_property_set());
+        assert_eq!(WellKnownObjectType::User.to_string(), "user");
+    }
+}
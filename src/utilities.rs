@@ -1,11 +1,11 @@
 //! Minor utilities for working with Windows APIs.
 
-use crate::{wrappers, LocalBox, Sid};
+use crate::ffi::um::winnt::TOKEN_USER;
+use crate::{wrappers, Handle, LocalBox, Sid};
 use std::ffi::{OsStr, OsString};
 use std::io;
 use std::os::windows::ffi::{OsStrExt, OsStringExt};
-use std::ptr::{null, null_mut};
-use winapi::um::winnt::{HANDLE, TOKEN_USER};
+use std::ptr::null;
 
 /// Create an `OsString` from a NUL-terminated buffer
 ///
@@ -133,22 +133,7 @@ pub fn ptr_from_opt<T>(opt: Option<&T>) -> *const T {
 
 /// Get the user SID of the current process
 pub fn current_process_sid() -> io::Result<LocalBox<Sid>> {
-    let mut process_token: HANDLE = null_mut();
-
-    // process_token must not be used until return value is checked
-    // process_token must be closed if return value is nonzero
-    let result = unsafe {
-        winapi::um::processthreadsapi::OpenProcessToken(
-            winapi::um::processthreadsapi::GetCurrentProcess(),
-            winapi::um::winnt::TOKEN_QUERY,
-            &mut process_token,
-        )
-    };
-
-    if result == 0 {
-        // Failed; no need for cleanup
-        return Err(io::Error::last_os_error());
-    }
+    let process_token = Handle::open_process_token(crate::ffi::um::winnt::TOKEN_QUERY)?;
 
     // Experimentation suggests that 44 bytes is the normal space required on
     // x64. Will automatically reallocate later if that's not enough
@@ -159,9 +144,9 @@ pub fn current_process_sid() -> io::Result<LocalBox<Sid>> {
         token_info = vec![0u8; len as usize];
 
         let result = unsafe {
-            winapi::um::securitybaseapi::GetTokenInformation(
-                process_token,
-                winapi::um::winnt::TokenUser,
+            crate::ffi::um::securitybaseapi::GetTokenInformation(
+                process_token.as_raw() as _,
+                crate::ffi::um::winnt::TokenUser,
                 token_info.as_mut_ptr() as *mut _,
                 len,
                 &mut len,
@@ -171,23 +156,19 @@ pub fn current_process_sid() -> io::Result<LocalBox<Sid>> {
         if result != 0 {
             // Success!
             break;
-        } else {
-            // Save off the error code before CloseHandle in case CloseHandle has
-            // its own error
-            let error_code = io::Error::last_os_error();
-
-            // If we got error code 122, try again
-            // len was updated to the new size by the API call
-            if error_code.raw_os_error()
-                == Some(winapi::shared::winerror::ERROR_INSUFFICIENT_BUFFER as i32)
-            {
-                continue;
-            }
-
-            unsafe { winapi::um::handleapi::CloseHandle(process_token) };
-
-            return Err(error_code);
         }
+
+        let error_code = io::Error::last_os_error();
+
+        // If we got error code 122, try again
+        // len was updated to the new size by the API call
+        if error_code.raw_os_error()
+            == Some(crate::ffi::shared::winerror::ERROR_INSUFFICIENT_BUFFER as i32)
+        {
+            continue;
+        }
+
+        return Err(error_code);
     }
 
     // Read from the inside out:
@@ -201,13 +182,7 @@ pub fn current_process_sid() -> io::Result<LocalBox<Sid>> {
     // - Take the reference as a safe &Sid
     let sid_ref = unsafe { &*((*(token_info.as_ptr() as *const TOKEN_USER)).User.Sid as *mut Sid) };
 
-    let sid_copy = wrappers::CopySid(sid_ref);
-
-    unsafe {
-        winapi::um::handleapi::CloseHandle(process_token);
-    }
-
-    sid_copy
+    wrappers::CopySid(sid_ref)
 }
 
 #[cfg(test)]
@@ -0,0 +1,92 @@
+use crate::constants::AccessRights;
+use crate::ffi::shared::guiddef::GUID;
+use crate::ffi::shared::winerror::{ERROR_INSUFFICIENT_BUFFER, ERROR_SUCCESS};
+use crate::ffi::um::winnt::{OBJECT_TYPE_LIST, PRIVILEGE_SET};
+use crate::{GenericMapping, Handle, ObjectTypeListEntry, SecurityDescriptor, Sid};
+use std::io;
+use std::mem;
+use std::ptr::null_mut;
+
+/// Wraps [`AccessCheckByTypeResultList`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-accesscheckbytyperesultlist)
+///
+/// `token` must be an impersonation token opened with `TOKEN_QUERY` access.
+/// `desired` must not contain generic rights; map them first with
+/// [`GenericMapping::map`].
+///
+/// The outer result reports whether the check could be performed at all. On
+/// success, there is one inner result per entry in `object_types`, holding
+/// either the access granted for that node or the reason it was denied.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(token = ?token.as_raw(), ?desired, object_types = object_types.len()), err(level = "debug"))
+)]
+#[allow(non_snake_case)]
+pub fn AccessCheckByTypeResultList(
+    sd: &SecurityDescriptor,
+    principal_self: Option<&Sid>,
+    token: &Handle,
+    desired: AccessRights,
+    object_types: &[ObjectTypeListEntry],
+    mapping: &GenericMapping,
+) -> io::Result<Vec<io::Result<AccessRights>>> {
+    let mut type_list: Vec<OBJECT_TYPE_LIST> = object_types
+        .iter()
+        .map(|entry| OBJECT_TYPE_LIST {
+            Level: entry.level,
+            Sbz: 0,
+            ObjectType: entry.object_type.as_ptr() as *mut GUID,
+        })
+        .collect();
+
+    let mut raw_mapping = mapping.to_raw();
+    let mut granted = vec![0u32; object_types.len()];
+    let mut statuses = vec![0u32; object_types.len()];
+
+    // PRIVILEGE_SET only needs 4-byte alignment, so a u32 buffer is enough
+    let mut privileges: Vec<u32> = vec![0; mem::size_of::<PRIVILEGE_SET>() / 4];
+
+    loop {
+        let mut privileges_len = (privileges.len() * 4) as u32;
+
+        let result = unsafe {
+            crate::ffi::um::securitybaseapi::AccessCheckByTypeResultList(
+                sd as *const _ as *mut _,
+                principal_self.map_or(null_mut(), |s| s as *const _ as *mut _),
+                token.as_raw() as _,
+                desired.bits(),
+                type_list.as_mut_ptr(),
+                type_list.len() as u32,
+                &mut raw_mapping,
+                privileges.as_mut_ptr() as *mut _,
+                &mut privileges_len,
+                granted.as_mut_ptr(),
+                statuses.as_mut_ptr(),
+            )
+        };
+
+        if result != 0 {
+            break;
+        }
+
+        let err = io::Error::last_os_error();
+        if err.raw_os_error() == Some(ERROR_INSUFFICIENT_BUFFER as i32)
+            && privileges_len as usize > privileges.len() * 4
+        {
+            privileges.resize((privileges_len as usize).div_ceil(4), 0);
+        } else {
+            return Err(err);
+        }
+    }
+
+    Ok(granted
+        .into_iter()
+        .zip(statuses)
+        .map(|(mask, status)| {
+            if status == ERROR_SUCCESS {
+                Ok(AccessRights::from_bits_truncate(mask))
+            } else {
+                Err(io::Error::from_raw_os_error(status as i32))
+            }
+        })
+        .collect())
+}
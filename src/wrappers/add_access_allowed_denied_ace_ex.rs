@@ -0,0 +1,45 @@
+use crate::constants::{AccessRights, AceFlags};
+use crate::{Acl, Sid};
+use std::io;
+
+macro_rules! add_access_ace_ex {
+    ($f:ident; msdn: $msdn:expr) => {
+        add_access_ace_ex!(@ $f, concat!("Wraps [`", stringify!($f), "`](", $msdn, ")"));
+    };
+    (@ $f:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// The ACE is appended to the end of the ACL, which must have enough
+        /// free space to hold it. The ACE is added with the `ACL_REVISION`
+        /// revision, which is compatible with every ACL revision.
+        #[allow(non_snake_case)]
+        pub fn $f(
+            acl: &mut Acl,
+            flags: AceFlags,
+            mask: AccessRights,
+            sid: &Sid,
+        ) -> io::Result<()> {
+            let result = unsafe {
+                crate::ffi::um::securitybaseapi::$f(
+                    acl as *mut _ as *mut _,
+                    crate::ffi::um::winnt::ACL_REVISION as u32,
+                    flags.bits() as u32,
+                    mask.bits(),
+                    sid as *const _ as *mut _,
+                )
+            };
+
+            if result == 0 {
+                Err(io::Error::last_os_error())
+            } else {
+                Ok(())
+            }
+        }
+    };
+}
+
+add_access_ace_ex!(AddAccessAllowedAceEx;
+    msdn: "https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessallowedaceex");
+
+add_access_ace_ex!(AddAccessDeniedAceEx;
+    msdn: "https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessdeniedaceex");
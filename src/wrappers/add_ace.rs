@@ -8,9 +8,9 @@ use std::io;
 #[allow(non_snake_case)]
 pub fn AddAce(acl: &mut Acl, index: u32, ace: &Ace) -> io::Result<()> {
     let result = unsafe {
-        winapi::um::securitybaseapi::AddAce(
+        crate::ffi::um::securitybaseapi::AddAce(
             acl as *mut _ as *mut _,
-            winapi::um::winnt::ACL_REVISION_DS as u32, // Only handles new-style ACLs
+            crate::ffi::um::winnt::ACL_REVISION_DS as u32, // Only handles new-style ACLs
             index,
             ace as *const _ as *mut _,
             1, // Just one in the list
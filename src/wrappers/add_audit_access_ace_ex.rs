@@ -0,0 +1,36 @@
+use crate::constants::{AccessRights, AceFlags};
+use crate::{Acl, Sid};
+use std::io;
+
+/// Wraps [`AddAuditAccessAceEx`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addauditaccessaceex)
+///
+/// The ACE is appended to the end of the ACL, which must have enough free
+/// space to hold it. `audit_success` and `audit_failure` set the
+/// `SuccessfulAccess` and `FailedAccess` flags on the new ACE.
+#[allow(non_snake_case)]
+pub fn AddAuditAccessAceEx(
+    acl: &mut Acl,
+    flags: AceFlags,
+    mask: AccessRights,
+    sid: &Sid,
+    audit_success: bool,
+    audit_failure: bool,
+) -> io::Result<()> {
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::AddAuditAccessAceEx(
+            acl as *mut _ as *mut _,
+            crate::ffi::um::winnt::ACL_REVISION as u32,
+            flags.bits() as u32,
+            mask.bits(),
+            sid as *const _ as *mut _,
+            audit_success as i32,
+            audit_failure as i32,
+        )
+    };
+
+    if result == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use crate::constants::{AceFlags, MandatoryPolicy};
+use crate::{Acl, Sid};
+use std::io;
+
+/// Wraps [`AddMandatoryAce`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addmandatoryace)
+///
+/// The ACE is appended to the end of the ACL, which must have enough free
+/// space to hold it. `label` must be a mandatory label SID (`S-1-16-x`).
+/// The ACE is added with the `ACL_REVISION` revision, which is compatible
+/// with every ACL revision.
+#[allow(non_snake_case)]
+pub fn AddMandatoryAce(
+    acl: &mut Acl,
+    flags: AceFlags,
+    policy: MandatoryPolicy,
+    label: &Sid,
+) -> io::Result<()> {
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::AddMandatoryAce(
+            acl as *mut _ as *mut _,
+            crate::ffi::um::winnt::ACL_REVISION as u32,
+            flags.bits() as u32,
+            policy.bits(),
+            label as *const _ as *mut _,
+        )
+    };
+
+    if result == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
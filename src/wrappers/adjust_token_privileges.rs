@@ -0,0 +1,84 @@
+use crate::ffi::shared::winerror::ERROR_NOT_ALL_ASSIGNED;
+use crate::ffi::um::winnt::{LUID, LUID_AND_ATTRIBUTES, SE_PRIVILEGE_ENABLED, TOKEN_PRIVILEGES};
+use crate::structures::Luid;
+use crate::Handle;
+use std::io;
+use std::mem;
+
+/// Wraps [`AdjustTokenPrivileges`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-adjusttokenprivileges)
+///
+/// Enables or disables a single privilege. `token` must be opened with
+/// `TOKEN_ADJUST_PRIVILEGES | TOKEN_QUERY` access.
+///
+/// Returns whether the privilege was enabled before the call, so it can be
+/// restored later. If the token does not hold the privilege at all, this
+/// fails with `ERROR_NOT_ALL_ASSIGNED`.
+#[allow(non_snake_case)]
+pub fn AdjustTokenPrivileges(token: &Handle, privilege: Luid, enable: bool) -> io::Result<bool> {
+    let mut new_state = TOKEN_PRIVILEGES {
+        PrivilegeCount: 1,
+        Privileges: [LUID_AND_ATTRIBUTES {
+            Luid: privilege.to_raw(),
+            Attributes: if enable { SE_PRIVILEGE_ENABLED } else { 0 },
+        }],
+    };
+
+    let mut previous_state = TOKEN_PRIVILEGES {
+        PrivilegeCount: 0,
+        Privileges: [LUID_AND_ATTRIBUTES {
+            Luid: LUID {
+                LowPart: 0,
+                HighPart: 0,
+            },
+            Attributes: 0,
+        }],
+    };
+    let mut previous_len = 0u32;
+
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::AdjustTokenPrivileges(
+            token.as_raw() as _,
+            0,
+            &mut new_state,
+            mem::size_of::<TOKEN_PRIVILEGES>() as u32,
+            &mut previous_state,
+            &mut previous_len,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    // The call succeeds even when the privilege could not be adjusted, and
+    // reports that through the last error instead
+    let error = io::Error::last_os_error();
+    if error.raw_os_error() == Some(ERROR_NOT_ALL_ASSIGNED as i32) {
+        return Err(error);
+    }
+
+    // PreviousState is empty when the call did not change anything
+    Ok(if previous_state.PrivilegeCount == 0 {
+        enable
+    } else {
+        previous_state.Privileges[0].Attributes & SE_PRIVILEGE_ENABLED != 0
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ffi::um::winnt::{SE_CHANGE_NOTIFY_NAME, TOKEN_ADJUST_PRIVILEGES, TOKEN_QUERY};
+    use crate::wrappers::{LookupPrivilegeValue, OpenProcessToken};
+
+    #[test]
+    fn change_notify_is_enabled() -> io::Result<()> {
+        // Every token holds SeChangeNotifyPrivilege, enabled by default
+        let token = OpenProcessToken(TOKEN_ADJUST_PRIVILEGES | TOKEN_QUERY)?;
+        let luid = LookupPrivilegeValue(SE_CHANGE_NOTIFY_NAME)?;
+
+        assert!(AdjustTokenPrivileges(&token, luid, true)?);
+
+        Ok(())
+    }
+}
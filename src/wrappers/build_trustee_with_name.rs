@@ -9,8 +9,8 @@ pub fn BuildTrusteeWithName<'s>(name_buf: &'s [u16]) -> Trustee<'s> {
     let mut trustee = unsafe { Trustee::allocate() };
 
     unsafe {
-        winapi::um::aclapi::BuildTrusteeWithNameW(
-            trustee.as_mut_ptr(),
+        crate::ffi::um::aclapi::BuildTrusteeWithNameW(
+            trustee.as_mut_ptr() as *mut _,
             name_buf.as_ptr() as *mut _,
         );
     }
@@ -19,10 +19,20 @@ pub fn BuildTrusteeWithName<'s>(name_buf: &'s [u16]) -> Trustee<'s> {
 }
 
 /// Copies the `OsStr` into WTF-16 before creating the `Trustee`.
+///
+/// The `Trustee` owns the copy and frees it when dropped.
 #[allow(non_snake_case)]
 pub fn BuildTrusteeWithNameOsStr(name: &OsStr) -> Trustee<'static> {
-    // Convert name into a static WTF-16 buffer
-    let buffer: &'static [u16] = Box::leak(utilities::buf_from_os(name).into_boxed_slice());
+    let buffer = utilities::buf_from_os(name).into_boxed_slice();
+    let mut trustee = unsafe { Trustee::allocate() };
+
+    unsafe {
+        crate::ffi::um::aclapi::BuildTrusteeWithNameW(
+            trustee.as_mut_ptr() as *mut _,
+            buffer.as_ptr() as *mut _,
+        );
+        trustee.own_name(buffer);
+    }
 
-    BuildTrusteeWithName(&buffer)
+    trustee
 }
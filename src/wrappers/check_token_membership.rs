@@ -0,0 +1,84 @@
+use crate::{Handle, Sid};
+use std::io;
+use std::ptr::null_mut;
+
+/// Wraps [`CheckTokenMembership`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-checktokenmembership)
+///
+/// Checks whether `sid` is enabled in a token: either it is the token's user,
+/// or it is one of the token's groups and is not deny-only. This is the
+/// correct way to check whether a token is an administrator, since an
+/// unelevated administrator has `BUILTIN\Administrators` as a deny-only
+/// group.
+///
+/// If `token` is `None`, the effective token of the current thread is used.
+/// Otherwise, `token` must be an impersonation token opened with
+/// `TOKEN_QUERY` access.
+///
+/// ```
+/// use windows_permissions::{wrappers, Sid};
+/// use winapi::um::winnt::{WinBuiltinAdministratorsSid, WinWorldSid};
+///
+/// let everyone = Sid::well_known_sid(WinWorldSid).unwrap();
+/// assert!(wrappers::CheckTokenMembership(None, &everyone).unwrap());
+///
+/// let admins = Sid::well_known_sid(WinBuiltinAdministratorsSid).unwrap();
+/// let elevated = wrappers::CheckTokenMembership(None, &admins).unwrap();
+/// println!("Running as an administrator: {}", elevated);
+/// ```
+#[allow(non_snake_case)]
+pub fn CheckTokenMembership(token: Option<&Handle>, sid: &Sid) -> io::Result<bool> {
+    let mut is_member = 0;
+
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::CheckTokenMembership(
+            token.map_or(null_mut(), Handle::as_raw),
+            sid as *const _ as *mut _,
+            &mut is_member,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(is_member != 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ffi::um::winnt::WinWorldSid;
+    use crate::LocalBox;
+
+    #[test]
+    fn everyone_is_a_member() -> io::Result<()> {
+        let everyone = Sid::well_known_sid(WinWorldSid)?;
+        let token = Handle::impersonation_token();
+
+        assert!(CheckTokenMembership(None, &everyone)?);
+        assert!(CheckTokenMembership(Some(&token), &everyone)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn made_up_sid_is_not_a_member() -> io::Result<()> {
+        let made_up: LocalBox<Sid> = "S-1-5-21-1111111111-2222222222-3333333333-4444".parse()?;
+        let token = Handle::impersonation_token();
+
+        assert!(!CheckTokenMembership(None, &made_up)?);
+        assert!(!CheckTokenMembership(Some(&token), &made_up)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn primary_token_is_rejected() -> io::Result<()> {
+        let everyone = Sid::well_known_sid(WinWorldSid)?;
+        let token = crate::wrappers::OpenProcessToken(crate::ffi::um::winnt::TOKEN_QUERY)?;
+
+        assert!(CheckTokenMembership(Some(&token), &everyone).is_err());
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::constants::SecurityInformation;
+use crate::constants::{SddlRevision, SecurityInformation};
 use crate::utilities;
 use crate::SecurityDescriptor;
 use std::ffi::OsString;
@@ -8,7 +8,8 @@ use std::slice;
 
 /// Wraps [`ConvertSecurityDescriptorToStringSecurityDescriptorW`](https://docs.microsoft.com/en-us/windows/win32/api/sddl/nf-sddl-convertsecuritydescriptortostringsecuritydescriptorw)
 ///
-/// This always uses `SDDL_REVISION_1` as the SDDL revision.
+/// This always uses `SDDL_REVISION_1` as the SDDL revision. To choose the
+/// revision, use [`ConvertSecurityDescriptorToStringSecurityDescriptorWithRevision`].
 ///
 /// It may be more convenient to use [`SecurityDescriptor::as_sddl`] when all
 /// security information is needed.
@@ -31,15 +32,51 @@ use std::slice;
 pub fn ConvertSecurityDescriptorToStringSecurityDescriptor(
     sd: &SecurityDescriptor,
     info: SecurityInformation,
+) -> io::Result<OsString> {
+    ConvertSecurityDescriptorToStringSecurityDescriptorWithRevision(
+        sd,
+        info,
+        SddlRevision::SDDL_REVISION_1,
+    )
+}
+
+/// Wraps [`ConvertSecurityDescriptorToStringSecurityDescriptorW`](https://docs.microsoft.com/en-us/windows/win32/api/sddl/nf-sddl-convertsecuritydescriptortostringsecuritydescriptorw)
+/// with a specific SDDL revision
+///
+/// ```
+/// use windows_permissions::wrappers::ConvertSecurityDescriptorToStringSecurityDescriptorWithRevision;
+/// use windows_permissions::{LocalBox, SecurityDescriptor};
+/// use windows_permissions::constants::{SddlRevision, SecurityInformation};
+///
+/// let string_sd = "G:S-1-5-10-20";
+/// let sd: LocalBox<SecurityDescriptor> = string_sd.parse().unwrap();
+///
+/// let string_sd2 = ConvertSecurityDescriptorToStringSecurityDescriptorWithRevision(
+///     &sd,
+///     SecurityInformation::all(),
+///     SddlRevision::SDDL_REVISION_1,
+/// ).unwrap();
+///
+/// assert_eq!(string_sd, &string_sd2);
+/// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(?info, ?revision), err(level = "debug"))
+)]
+#[allow(non_snake_case)]
+pub fn ConvertSecurityDescriptorToStringSecurityDescriptorWithRevision(
+    sd: &SecurityDescriptor,
+    info: SecurityInformation,
+    revision: SddlRevision,
 ) -> io::Result<OsString> {
     let mut buf_ptr: *mut u16 = null_mut();
     let mut buf_len: u32 = 0;
 
     // If success, buf_ptr must be LocalFree'd
     let result = unsafe {
-        winapi::shared::sddl::ConvertSecurityDescriptorToStringSecurityDescriptorW(
+        crate::ffi::shared::sddl::ConvertSecurityDescriptorToStringSecurityDescriptorW(
             sd as *const _ as *mut _,
-            winapi::shared::sddl::SDDL_REVISION_1.into(),
+            revision as u32,
             info.bits(),
             &mut buf_ptr,
             &mut buf_len,
@@ -55,7 +92,7 @@ pub fn ConvertSecurityDescriptorToStringSecurityDescriptor(
 
     let string = utilities::os_from_buf(slice);
 
-    unsafe { winapi::um::winbase::LocalFree(buf_ptr as *mut _) };
+    unsafe { crate::ffi::um::winbase::LocalFree(buf_ptr as *mut _) };
 
     Ok(string)
 }
@@ -18,6 +18,10 @@ use std::ptr::{null_mut, NonNull};
 /// let sd = ConvertStringSecurityDescriptorToSecurityDescriptor(string_sd).unwrap();
 /// assert_eq!(string_sd, &sd.as_sddl().unwrap());
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(string = ?string.as_ref()), err(level = "debug"))
+)]
 #[allow(non_snake_case)]
 pub fn ConvertStringSecurityDescriptorToSecurityDescriptor<S: AsRef<OsStr> + ?Sized>(
     string: &S,
@@ -26,9 +30,9 @@ pub fn ConvertStringSecurityDescriptorToSecurityDescriptor<S: AsRef<OsStr> + ?Si
     let mut sd_ptr = null_mut();
 
     let result = unsafe {
-        winapi::shared::sddl::ConvertStringSecurityDescriptorToSecurityDescriptorW(
+        crate::ffi::shared::sddl::ConvertStringSecurityDescriptorToSecurityDescriptorW(
             buffer.as_ptr(),
-            winapi::shared::sddl::SDDL_REVISION_1.into(),
+            crate::ffi::shared::sddl::SDDL_REVISION_1.into(),
             &mut sd_ptr,
             null_mut(),
         )
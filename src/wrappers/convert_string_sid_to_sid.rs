@@ -18,12 +18,17 @@ use std::ptr::{null_mut, NonNull};
 ///
 /// Panics if the underlying WinAPI call reports success but returns a null
 /// pointer. This should never happen.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(string = ?string.as_ref()), err(level = "debug"))
+)]
 #[allow(non_snake_case)]
 pub fn ConvertStringSidToSid<S: AsRef<OsStr> + ?Sized>(string: &S) -> io::Result<LocalBox<Sid>> {
     let buf = buf_from_os(string);
     let mut ptr = null_mut();
 
-    let result = unsafe { winapi::shared::sddl::ConvertStringSidToSidW(buf.as_ptr(), &mut ptr) };
+    let result =
+        unsafe { crate::ffi::shared::sddl::ConvertStringSidToSidW(buf.as_ptr(), &mut ptr) };
 
     if result != 0 {
         // Success
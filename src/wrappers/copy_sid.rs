@@ -27,7 +27,7 @@ pub fn CopySid(sid: &Sid) -> io::Result<LocalBox<Sid>> {
     let new_sid: LocalBox<Sid> = unsafe { LocalBox::try_allocate(true, size)? };
 
     let success = unsafe {
-        winapi::um::securitybaseapi::CopySid(
+        crate::ffi::um::securitybaseapi::CopySid(
             size as u32,
             new_sid.as_ptr() as *mut _,
             sid as *const _ as *mut _,
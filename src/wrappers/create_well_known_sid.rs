@@ -18,6 +18,10 @@ use std::ptr::null_mut;
 ///
 /// assert_eq!(win_world_sid, another_sid);
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(sid_type, domain_sid = ?domain_sid.map(ToString::to_string)), err(level = "debug"))
+)]
 #[allow(non_snake_case)]
 pub fn CreateWellKnownSid(sid_type: u32, domain_sid: Option<&Sid>) -> io::Result<LocalBox<Sid>> {
     // Optimistically reserve enough space for a fairly large SID
@@ -33,7 +37,7 @@ pub fn CreateWellKnownSid(sid_type: u32, domain_sid: Option<&Sid>) -> io::Result
     let new_sid: LocalBox<Sid> = unsafe { LocalBox::try_allocate(true, sid_len as usize)? };
 
     let result = unsafe {
-        winapi::um::securitybaseapi::CreateWellKnownSid(
+        crate::ffi::um::securitybaseapi::CreateWellKnownSid(
             sid_type,
             domain_sid_ptr,
             new_sid.as_ptr() as *mut _,
@@ -0,0 +1,31 @@
+use crate::Acl;
+use std::io;
+
+/// Wraps [`DeleteAce`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-deleteace)
+///
+/// The ACEs after `index` move up by one, and the space the ACE used becomes
+/// free space at the end of the ACL.
+///
+/// ```
+/// use windows_permissions::{LocalBox, SecurityDescriptor};
+/// use windows_permissions::wrappers::DeleteAce;
+///
+/// let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;SY)(A;;FR;;;WD)".parse().unwrap();
+/// let mut acl = sd.dacl().unwrap().to_owned().unwrap();
+///
+/// DeleteAce(&mut acl, 0).unwrap();
+///
+/// assert_eq!(acl.len(), 1);
+/// assert!(DeleteAce(&mut acl, 1).is_err());
+/// ```
+#[allow(non_snake_case)]
+pub fn DeleteAce(acl: &mut Acl, index: u32) -> io::Result<()> {
+    let result =
+        unsafe { crate::ffi::um::securitybaseapi::DeleteAce(acl as *mut _ as *mut _, index) };
+
+    if result == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
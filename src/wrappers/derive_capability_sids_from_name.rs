@@ -0,0 +1,76 @@
+use crate::ffi::um::winnt::PSID;
+use crate::utilities::buf_from_os;
+use crate::{LocalBox, Sid};
+use std::ffi::OsStr;
+use std::io;
+use std::ptr::{null_mut, NonNull};
+
+/// Wraps [`DeriveCapabilitySidsFromName`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-derivecapabilitysidsfromname)
+///
+/// Returns (capability group SIDs, capability SIDs).
+///
+/// ```
+/// use windows_permissions::wrappers::DeriveCapabilitySidsFromName;
+///
+/// let (group_sids, sids) = DeriveCapabilitySidsFromName("internetClient").unwrap();
+///
+/// assert!(sids[0].to_string().starts_with("S-1-15-3-"));
+/// assert!(group_sids[0].to_string().starts_with("S-1-5-32-"));
+/// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(name = ?name.as_ref()), err(level = "debug"))
+)]
+#[allow(non_snake_case, clippy::type_complexity)]
+pub fn DeriveCapabilitySidsFromName<S: AsRef<OsStr> + ?Sized>(
+    name: &S,
+) -> io::Result<(Vec<LocalBox<Sid>>, Vec<LocalBox<Sid>>)> {
+    let name = buf_from_os(name);
+
+    let mut group_sids: *mut PSID = null_mut();
+    let mut group_sid_count = 0u32;
+    let mut sids: *mut PSID = null_mut();
+    let mut sid_count = 0u32;
+
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::DeriveCapabilitySidsFromName(
+            name.as_ptr(),
+            &mut group_sids,
+            &mut group_sid_count,
+            &mut sids,
+            &mut sid_count,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    // Each SID and each array is allocated with LocalAlloc. The SIDs are
+    // taken over by LocalBoxes, and the arrays are freed here.
+    let group_sids = unsafe { take_sids(group_sids, group_sid_count) };
+    let sids = unsafe { take_sids(sids, sid_count) };
+
+    Ok((group_sids, sids))
+}
+
+/// Move the SIDs out of a LocalAlloc'd array, then free the array
+unsafe fn take_sids(array: *mut PSID, count: u32) -> Vec<LocalBox<Sid>> {
+    if array.is_null() {
+        return Vec::new();
+    }
+
+    let sids = std::slice::from_raw_parts(array, count as usize)
+        .iter()
+        .map(|&sid| {
+            LocalBox::from_raw(
+                NonNull::new(sid as *mut _)
+                    .expect("DeriveCapabilitySidsFromName returned a null SID"),
+            )
+        })
+        .collect();
+
+    crate::ffi::um::winbase::LocalFree(array as *mut _);
+
+    sids
+}
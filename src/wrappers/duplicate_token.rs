@@ -0,0 +1,79 @@
+use crate::constants::SecurityImpersonationLevel;
+use crate::Handle;
+use std::io;
+use std::ptr::null_mut;
+
+/// Wraps [`DuplicateToken`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-duplicatetoken)
+///
+/// Creates an impersonation token at `level` from an existing token, which
+/// must have been opened with `TOKEN_DUPLICATE` access. The new token has
+/// `TOKEN_IMPERSONATE` and `TOKEN_QUERY` access.
+///
+/// ```
+/// use windows_permissions::{wrappers, Handle};
+/// use windows_permissions::constants::SecurityImpersonationLevel;
+/// use winapi::um::winnt::TOKEN_DUPLICATE;
+///
+/// let process_token = Handle::open_process_token(TOKEN_DUPLICATE).unwrap();
+/// let token = wrappers::DuplicateToken(
+///     &process_token,
+///     SecurityImpersonationLevel::SecurityIdentification,
+/// )
+/// .unwrap();
+///
+/// assert!(token.is_owned());
+/// ```
+#[allow(non_snake_case)]
+pub fn DuplicateToken(token: &Handle, level: SecurityImpersonationLevel) -> io::Result<Handle> {
+    let mut duplicate = null_mut();
+
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::DuplicateToken(
+            token.as_raw() as _,
+            level as u32,
+            &mut duplicate,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(unsafe { Handle::from_raw_owned(duplicate as _) })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ffi::shared::winerror::ERROR_ACCESS_DENIED;
+    use crate::ffi::um::winnt::{TOKEN_DUPLICATE, TOKEN_QUERY};
+
+    #[test]
+    fn duplicate_process_token() -> io::Result<()> {
+        let process_token = Handle::open_process_token(TOKEN_DUPLICATE)?;
+
+        let token = DuplicateToken(
+            &process_token,
+            SecurityImpersonationLevel::SecurityImpersonation,
+        )?;
+        assert!(token.is_owned());
+        assert_ne!(token.as_raw(), process_token.as_raw());
+
+        Ok(())
+    }
+
+    #[test]
+    fn needs_duplicate_access() -> io::Result<()> {
+        let process_token = Handle::open_process_token(TOKEN_QUERY)?;
+
+        let error = DuplicateToken(
+            &process_token,
+            SecurityImpersonationLevel::SecurityImpersonation,
+        )
+        .err()
+        .and_then(|e| e.raw_os_error());
+        assert_eq!(error, Some(ERROR_ACCESS_DENIED as i32));
+
+        Ok(())
+    }
+}
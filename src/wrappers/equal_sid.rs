@@ -20,7 +20,7 @@ use crate::Sid;
 #[allow(non_snake_case)]
 pub fn EqualSid(sid1: &Sid, sid2: &Sid) -> bool {
     (unsafe {
-        winapi::um::securitybaseapi::EqualSid(
+        crate::ffi::um::securitybaseapi::EqualSid(
             sid1 as *const _ as *mut _,
             sid2 as *const _ as *mut _,
         )
@@ -1,12 +1,13 @@
+use crate::ffi::um::winnt::ACL_SIZE_INFORMATION;
+use crate::structures::AclSizeInformation;
 use crate::Acl;
 use std::io;
-use winapi::um::winnt::ACL_SIZE_INFORMATION;
 
 /// Wraps [`GetAclInformation`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getaclinformation)
 ///
 /// Always uses [`ACL_SIZE_INFORMATION`](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-acl_size_information) as the information class.
 #[allow(non_snake_case)]
-pub fn GetAclInformationSize(acl: &Acl) -> io::Result<ACL_SIZE_INFORMATION> {
+pub fn GetAclInformationSize(acl: &Acl) -> io::Result<AclSizeInformation> {
     debug_assert!(crate::wrappers::IsValidAcl(acl));
 
     let mut info = ACL_SIZE_INFORMATION {
@@ -18,11 +19,11 @@ pub fn GetAclInformationSize(acl: &Acl) -> io::Result<ACL_SIZE_INFORMATION> {
     let info_size = std::mem::size_of::<ACL_SIZE_INFORMATION>() as u32;
 
     let result = unsafe {
-        winapi::um::securitybaseapi::GetAclInformation(
+        crate::ffi::um::securitybaseapi::GetAclInformation(
             acl as *const _ as *mut _,
             &mut info as *mut _ as *mut _,
             info_size,
-            winapi::um::winnt::AclSizeInformation,
+            crate::ffi::um::winnt::AclSizeInformation,
         )
     };
 
@@ -32,6 +33,10 @@ pub fn GetAclInformationSize(acl: &Acl) -> io::Result<ACL_SIZE_INFORMATION> {
         debug_assert!(info.AceCount != 0xDEADBEEF);
         debug_assert!(info.AclBytesInUse != 0xDEADBEEF);
         debug_assert!(info.AclBytesFree != 0xDEADBEEF);
-        Ok(info)
+        Ok(AclSizeInformation {
+            ace_count: info.AceCount,
+            bytes_in_use: info.AclBytesInUse,
+            bytes_free: info.AclBytesFree,
+        })
     }
 }
@@ -1,16 +1,20 @@
 use crate::constants::{SeObjectType, SecurityInformation};
+use crate::ffi::shared::winerror::ERROR_SUCCESS;
 use crate::utilities::buf_from_os;
 use crate::{LocalBox, SecurityDescriptor};
 use std::ffi::OsStr;
 use std::io;
 use std::ptr::{null_mut, NonNull};
-use winapi::shared::winerror::ERROR_SUCCESS;
 
 /// Wraps [`GetNamedSecurityInfoW`](https://docs.microsoft.com/en-us/windows/win32/api/aclapi/nf-aclapi-getnamedsecurityinfow)
 ///
 /// # Panics
 ///
 /// Panics if the underlying call reports success but yields a null pointer.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(name = ?name.as_ref(), ?obj_type, ?sec_info), err(level = "debug"))
+)]
 #[allow(non_snake_case)]
 pub fn GetNamedSecurityInfo<S: AsRef<OsStr> + ?Sized>(
     name: &S,
@@ -22,7 +26,7 @@ pub fn GetNamedSecurityInfo<S: AsRef<OsStr> + ?Sized>(
     let mut sd = null_mut();
 
     let result_code = unsafe {
-        winapi::um::aclapi::GetNamedSecurityInfoW(
+        crate::ffi::um::aclapi::GetNamedSecurityInfoW(
             name.as_ptr(),
             obj_type as u32,
             sec_info.bits(),
@@ -43,3 +47,77 @@ pub fn GetNamedSecurityInfo<S: AsRef<OsStr> + ?Sized>(
 
     Ok(unsafe { LocalBox::from_raw(sd) })
 }
+
+#[cfg(all(test, feature = "tracing"))]
+mod test {
+    use super::*;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Subscriber that stores the fields of every event it sees
+    #[derive(Default, Clone)]
+    struct CapturingSubscriber {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut String);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.push_str(&format!("{}={:?} ", field.name(), value));
+        }
+    }
+
+    impl Subscriber for CapturingSubscriber {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = String::new();
+            event.record(&mut FieldVisitor(&mut fields));
+            self.events.lock().unwrap().push(fields);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn failure_emits_error_code() {
+        let subscriber = CapturingSubscriber::default();
+
+        let err = tracing::subscriber::with_default(subscriber.clone(), || {
+            GetNamedSecurityInfo(
+                r"C:\this\path\does\not\exist",
+                SeObjectType::SE_FILE_OBJECT,
+                SecurityInformation::Dacl,
+            )
+            .unwrap_err()
+        });
+
+        let code = err.raw_os_error().unwrap();
+        let events = subscriber.events.lock().unwrap();
+
+        assert!(
+            events
+                .iter()
+                .any(|event| event.contains(&format!("os error {}", code))),
+            "No event with error code {} in {:?}",
+            code,
+            events
+        );
+    }
+}
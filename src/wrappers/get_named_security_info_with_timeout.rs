@@ -0,0 +1,132 @@
+use crate::constants::{SeObjectType, SecurityInformation};
+use crate::{wrappers, LocalBox, SecurityDescriptor};
+use std::ffi::OsStr;
+use std::io;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Call [`GetNamedSecurityInfo`](wrappers::GetNamedSecurityInfo), giving up
+/// after `timeout`
+///
+/// Reading the security of an object on a network share (such as
+/// `\\server\share\file`) can hang for a long time if the server is slow to
+/// respond. This makes the call on a worker thread and fails with
+/// [`io::ErrorKind::TimedOut`] if it does not finish in time.
+///
+/// A call that times out cannot be cancelled, so its thread is abandoned. It
+/// keeps running in the background until the underlying call returns, and
+/// its result is then discarded.
+///
+/// ```
+/// use std::time::Duration;
+/// use windows_permissions::constants::{SeObjectType, SecurityInformation};
+/// use windows_permissions::wrappers::GetNamedSecurityInfoWithTimeout;
+///
+/// let sd = GetNamedSecurityInfoWithTimeout(
+///     r"C:\Windows",
+///     SeObjectType::SE_FILE_OBJECT,
+///     SecurityInformation::Owner,
+///     Duration::from_secs(10),
+/// )
+/// .unwrap();
+///
+/// assert!(sd.owner().is_some());
+/// ```
+#[allow(non_snake_case)]
+pub fn GetNamedSecurityInfoWithTimeout<S: AsRef<OsStr> + ?Sized>(
+    name: &S,
+    obj_type: SeObjectType,
+    sec_info: SecurityInformation,
+    timeout: Duration,
+) -> io::Result<LocalBox<SecurityDescriptor>> {
+    let name = name.as_ref().to_owned();
+
+    run_with_timeout(timeout, move || {
+        wrappers::GetNamedSecurityInfo(&name, obj_type, sec_info)
+    })
+}
+
+/// Run `f` on a new thread, and wait up to `timeout` for it to finish
+fn run_with_timeout<T, F>(timeout: Duration, f: F) -> io::Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> io::Result<T> + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+
+    thread::Builder::new()
+        .name("GetNamedSecurityInfo".into())
+        .spawn(move || {
+            // If the caller timed out, nobody is listening and the result is
+            // dropped here
+            let _ = sender.send(f());
+        })?;
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("Call did not finish within {:?}", timeout),
+        )),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            Err(io::Error::other("Worker thread panicked"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn local_path_is_fast() -> io::Result<()> {
+        let file = tempfile::NamedTempFile::new()?;
+
+        let sd = GetNamedSecurityInfoWithTimeout(
+            file.path(),
+            SeObjectType::SE_FILE_OBJECT,
+            SecurityInformation::Owner | SecurityInformation::Dacl,
+            Duration::from_secs(30),
+        )?;
+
+        assert!(sd.owner().is_some());
+        assert!(sd.dacl().is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors_are_passed_through() {
+        let err = GetNamedSecurityInfoWithTimeout(
+            r"C:\this\path\does\not\exist",
+            SeObjectType::SE_FILE_OBJECT,
+            SecurityInformation::Owner,
+            Duration::from_secs(30),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn slow_call_times_out() {
+        let err = run_with_timeout(Duration::from_secs(0), || {
+            thread::sleep(Duration::from_secs(5));
+            Ok(())
+        })
+        .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+    }
+
+    #[test]
+    fn panicking_call_fails() {
+        let err = run_with_timeout(Duration::from_secs(30), || -> io::Result<()> {
+            panic!("Deliberate panic in worker thread")
+        })
+        .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::constants::SdControl;
+use crate::SecurityDescriptor;
+use std::io;
+
+/// Wraps [`GetSecurityDescriptorControl`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getsecuritydescriptorcontrol)
+///
+/// The revision returned by the underlying call is discarded; it is always
+/// `SECURITY_DESCRIPTOR_REVISION` for descriptors built by Windows.
+///
+/// ```
+/// use windows_permissions::{LocalBox, SecurityDescriptor};
+/// use windows_permissions::constants::SdControl;
+/// use windows_permissions::wrappers::GetSecurityDescriptorControl;
+///
+/// let sd: LocalBox<SecurityDescriptor> = "D:P(A;;FA;;;WD)".parse().unwrap();
+/// let control = GetSecurityDescriptorControl(&sd).unwrap();
+///
+/// assert!(control.contains(SdControl::SelfRelative));
+/// assert!(control.contains(SdControl::DaclPresent | SdControl::DaclProtected));
+/// assert!(!control.contains(SdControl::SaclPresent));
+/// ```
+#[allow(non_snake_case)]
+pub fn GetSecurityDescriptorControl(sd: &SecurityDescriptor) -> io::Result<SdControl> {
+    let mut control = 0u16;
+    let mut revision = 0u32;
+
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::GetSecurityDescriptorControl(
+            sd as *const _ as *mut _,
+            &mut control,
+            &mut revision,
+        )
+    };
+
+    if result == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(SdControl::from_bits_truncate(control))
+    }
+}
@@ -1,7 +1,7 @@
+use crate::ffi::um::winnt::PACL;
 use crate::{wrappers, Acl, SecurityDescriptor};
 use std::io;
 use std::ptr::null_mut;
-use winapi::um::winnt::PACL;
 
 macro_rules! get_security_descriptor_acl {
     ($f:ident; msdn: $msdn:expr) => {
@@ -9,6 +9,9 @@ macro_rules! get_security_descriptor_acl {
     };
     (@ $f:ident, $doc:expr) => {
         #[doc = $doc]
+        ///
+        /// Returns `None` both when the ACL is not present and when it is a
+        /// null ACL. Check the descriptor's control bits to tell them apart.
         #[allow(non_snake_case)]
         pub fn $f(sd: &SecurityDescriptor) -> io::Result<Option<&Acl>> {
             let mut present = 0i32;
@@ -16,7 +19,7 @@ macro_rules! get_security_descriptor_acl {
             let mut defaulted = 0i32;
 
             let result = unsafe {
-                winapi::um::securitybaseapi::$f(
+                crate::ffi::um::securitybaseapi::$f(
                     sd as *const _ as *mut _,
                     &mut present,
                     &mut acl_ptr,
@@ -28,18 +31,12 @@ macro_rules! get_security_descriptor_acl {
                 // Failed
                 Err(io::Error::last_os_error())
             } else {
-                if present == 0 {
-                    // Not present
+                if present == 0 || acl_ptr.is_null() {
+                    // Not present, or a null ACL
                     Ok(None)
                 } else {
                     // Present
-                    let acl = unsafe {
-                        if acl_ptr.is_null() {
-                            panic!("$f indicated success but returned NULL");
-                        } else {
-                            &*(acl_ptr as *const _)
-                        }
-                    };
+                    let acl = unsafe { &*(acl_ptr as *const _) };
 
                     debug_assert!(wrappers::IsValidAcl(acl));
 
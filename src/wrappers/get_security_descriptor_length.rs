@@ -0,0 +1,22 @@
+use crate::SecurityDescriptor;
+
+/// Wraps [`GetSecurityDescriptorLength`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getsecuritydescriptorlength)
+///
+/// For an absolute descriptor, the length includes the components it points
+/// to, which are not necessarily stored next to the descriptor.
+///
+/// ```
+/// use windows_permissions::{LocalBox, SecurityDescriptor};
+/// use windows_permissions::wrappers::GetSecurityDescriptorLength;
+///
+/// let small: LocalBox<SecurityDescriptor> = "O:SY".parse().unwrap();
+/// let large: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse().unwrap();
+///
+/// assert!(GetSecurityDescriptorLength(&small) < GetSecurityDescriptorLength(&large));
+/// ```
+#[allow(non_snake_case)]
+pub fn GetSecurityDescriptorLength(sd: &SecurityDescriptor) -> u32 {
+    unsafe {
+        crate::ffi::um::securitybaseapi::GetSecurityDescriptorLength(sd as *const _ as *mut _)
+    }
+}
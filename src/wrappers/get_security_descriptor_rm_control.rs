@@ -0,0 +1,34 @@
+use crate::ffi::shared::winerror::{ERROR_INVALID_DATA, ERROR_SUCCESS};
+use crate::SecurityDescriptor;
+use std::io;
+
+/// Wraps [`GetSecurityDescriptorRMControl`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getsecuritydescriptorrmcontrol)
+///
+/// Returns `None` if the descriptor has no resource manager control bits,
+/// which the underlying call reports as `ERROR_INVALID_DATA`.
+///
+/// ```
+/// use windows_permissions::{LocalBox, SecurityDescriptor};
+/// use windows_permissions::wrappers::GetSecurityDescriptorRMControl;
+///
+/// let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;WD)".parse().unwrap();
+///
+/// assert_eq!(GetSecurityDescriptorRMControl(&sd).unwrap(), None);
+/// ```
+#[allow(non_snake_case)]
+pub fn GetSecurityDescriptorRMControl(sd: &SecurityDescriptor) -> io::Result<Option<u8>> {
+    let mut rm_control = 0u8;
+
+    let result_code = unsafe {
+        crate::ffi::um::securitybaseapi::GetSecurityDescriptorRMControl(
+            sd as *const _ as *mut _,
+            &mut rm_control,
+        )
+    };
+
+    match result_code {
+        ERROR_SUCCESS => Ok(Some(rm_control)),
+        ERROR_INVALID_DATA => Ok(None),
+        code => Err(io::Error::from_raw_os_error(code as i32)),
+    }
+}
@@ -1,15 +1,19 @@
 use crate::constants::{SeObjectType, SecurityInformation};
+use crate::ffi::shared::winerror::ERROR_SUCCESS;
 use crate::{LocalBox, SecurityDescriptor};
 use std::io;
 use std::os::windows::io::AsRawHandle;
 use std::ptr::{null_mut, NonNull};
-use winapi::shared::winerror::ERROR_SUCCESS;
 
 /// Wraps [`GetSecurityInfo`](https://docs.microsoft.com/en-us/windows/win32/api/aclapi/nf-aclapi-getsecurityinfo)
 ///
 /// # Panics
 ///
 /// Panics if the underlying call reports success but yields a null pointer.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(handle = ?handle.as_raw_handle(), ?obj_type, ?sec_info), err(level = "debug"))
+)]
 #[allow(non_snake_case)]
 pub fn GetSecurityInfo<H: AsRawHandle>(
     handle: &H,
@@ -19,7 +23,7 @@ pub fn GetSecurityInfo<H: AsRawHandle>(
     let mut sd = null_mut();
 
     let result_code = unsafe {
-        winapi::um::aclapi::GetSecurityInfo(
+        crate::ffi::um::aclapi::GetSecurityInfo(
             handle.as_raw_handle() as *mut _,
             obj_type as u32,
             sec_info.bits(),
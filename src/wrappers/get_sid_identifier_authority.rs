@@ -13,7 +13,7 @@ use crate::Sid;
 #[allow(non_snake_case)]
 pub fn GetSidIdentifierAuthority(sid: &Sid) -> &[u8; 6] {
     let ptr = unsafe {
-        &*winapi::um::securitybaseapi::GetSidIdentifierAuthority(sid as *const _ as *mut _)
+        &*crate::ffi::um::securitybaseapi::GetSidIdentifierAuthority(sid as *const _ as *mut _)
     };
     &ptr.Value
 }
@@ -13,5 +13,5 @@ use crate::Sid;
 /// ```
 #[allow(non_snake_case)]
 pub fn GetSidSubAuthorityCount(sid: &Sid) -> u8 {
-    unsafe { *winapi::um::securitybaseapi::GetSidSubAuthorityCount(sid as *const _ as *mut _) }
+    unsafe { *crate::ffi::um::securitybaseapi::GetSidSubAuthorityCount(sid as *const _ as *mut _) }
 }
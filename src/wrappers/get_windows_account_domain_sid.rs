@@ -1,8 +1,12 @@
+use crate::ffi::shared::winerror::ERROR_INSUFFICIENT_BUFFER;
 use crate::{wrappers, LocalBox, Sid};
 use std::io;
-use winapi::shared::winerror::ERROR_INSUFFICIENT_BUFFER;
 
 /// Wraps [`GetWindowsAccountDomainSid`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getwindowsaccountdomainsid)
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(sid = %sid), err(level = "debug"))
+)]
 #[allow(non_snake_case)]
 pub fn GetWindowsAccountDomainSid(sid: &Sid) -> io::Result<LocalBox<Sid>> {
     // 24 bytes is a typical size on x64
@@ -14,7 +18,7 @@ pub fn GetWindowsAccountDomainSid(sid: &Sid) -> io::Result<LocalBox<Sid>> {
         buffer = vec![0; len as usize];
 
         let result = unsafe {
-            winapi::um::securitybaseapi::GetWindowsAccountDomainSid(
+            crate::ffi::um::securitybaseapi::GetWindowsAccountDomainSid(
                 sid as *const _ as *mut _,
                 buffer.as_mut_ptr() as *mut _,
                 &mut len,
@@ -45,8 +49,8 @@ pub fn GetWindowsAccountDomainSid(sid: &Sid) -> io::Result<LocalBox<Sid>> {
 mod test {
     use super::*;
 
+    use crate::ffi::shared::winerror::ERROR_NON_ACCOUNT_SID;
     use crate::utilities;
-    use winapi::shared::winerror::ERROR_NON_ACCOUNT_SID;
 
     #[test]
     fn current_process_has_domain() {
@@ -57,7 +61,7 @@ mod test {
     fn well_known_sid_has_no_domain() {
         assert_eq!(
             GetWindowsAccountDomainSid(
-                &Sid::well_known_sid(winapi::um::winnt::WinWorldSid).unwrap()
+                &Sid::well_known_sid(crate::ffi::um::winnt::WinWorldSid).unwrap()
             )
             .unwrap_err()
             .raw_os_error(),
@@ -0,0 +1,19 @@
+use crate::constants::SecurityImpersonationLevel;
+use std::io;
+
+/// Wraps [`ImpersonateSelf`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-impersonateself)
+///
+/// Gives the current thread an impersonation token copied from the process
+/// token. Privileges adjusted on that token only affect this thread. Use
+/// [`SetThreadToken`](crate::wrappers::SetThreadToken) with `None` to stop
+/// impersonating.
+#[allow(non_snake_case)]
+pub fn ImpersonateSelf(level: SecurityImpersonationLevel) -> io::Result<()> {
+    let result = unsafe { crate::ffi::um::securitybaseapi::ImpersonateSelf(level as u32) };
+
+    if result == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::constants::AclRevision;
+use crate::{Acl, LocalBox};
+use std::convert::TryFrom;
+use std::io;
+
+/// Wraps [`InitializeAcl`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-initializeacl)
+///
+/// Allocates `size` bytes and initializes an empty ACL in them. `size` must
+/// be DWORD-aligned, at least the size of the ACL header, and fit in a `u16`.
+///
+/// ```
+/// use windows_permissions::wrappers::InitializeAcl;
+/// use windows_permissions::constants::AclRevision;
+///
+/// let acl = InitializeAcl(64, AclRevision::ACL_REVISION).unwrap();
+///
+/// assert_eq!(acl.len(), 0);
+/// assert_eq!(acl.revision_level(), AclRevision::ACL_REVISION);
+///
+/// assert!(InitializeAcl(65, AclRevision::ACL_REVISION).is_err());
+/// ```
+#[allow(non_snake_case)]
+pub fn InitializeAcl(size: u32, revision: AclRevision) -> io::Result<LocalBox<Acl>> {
+    if u16::try_from(size).is_err() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "InitializeAcl called with a size that does not fit in an ACL",
+        ));
+    }
+
+    let acl: LocalBox<Acl> = unsafe { LocalBox::try_allocate(true, size as usize)? };
+
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::InitializeAcl(
+            acl.as_ptr() as *mut _,
+            size,
+            revision as u32,
+        )
+    };
+
+    if result == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(acl)
+    }
+}
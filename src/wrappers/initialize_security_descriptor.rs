@@ -0,0 +1,37 @@
+use crate::ffi::um::winnt::{SECURITY_DESCRIPTOR, SECURITY_DESCRIPTOR_REVISION};
+use crate::{LocalBox, SecurityDescriptor};
+use std::io;
+use std::mem;
+
+/// Wraps [`InitializeSecurityDescriptor`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-initializesecuritydescriptor)
+///
+/// Allocates and initializes an absolute security descriptor with no owner,
+/// group, DACL, or SACL.
+///
+/// ```
+/// use windows_permissions::wrappers::InitializeSecurityDescriptor;
+/// use windows_permissions::constants::SdControl;
+///
+/// let sd = InitializeSecurityDescriptor().unwrap();
+///
+/// assert!(!sd.control().contains(SdControl::SelfRelative));
+/// assert_eq!(sd.owner(), None);
+/// ```
+#[allow(non_snake_case)]
+pub fn InitializeSecurityDescriptor() -> io::Result<LocalBox<SecurityDescriptor>> {
+    let sd: LocalBox<SecurityDescriptor> =
+        unsafe { LocalBox::try_allocate(true, mem::size_of::<SECURITY_DESCRIPTOR>())? };
+
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::InitializeSecurityDescriptor(
+            sd.as_ptr() as *mut _,
+            SECURITY_DESCRIPTOR_REVISION,
+        )
+    };
+
+    if result == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(sd)
+    }
+}
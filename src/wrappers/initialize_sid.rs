@@ -0,0 +1,79 @@
+use crate::ffi::um::winnt::SID_MAX_SUB_AUTHORITIES;
+use crate::{wrappers, LocalBox, Sid};
+use std::io;
+
+/// Wraps [`InitializeSid`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-initializesid)
+///
+/// Unlike [`AllocateAndInitializeSid`](wrappers::AllocateAndInitializeSid),
+/// this accepts any number of sub-authorities up to
+/// `SID_MAX_SUB_AUTHORITIES` (15), including none at all. The sub-authorities
+/// are filled in after the SID is initialized.
+///
+/// ```
+/// use windows_permissions::wrappers::InitializeSid;
+///
+/// let sub_auths = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+/// let sid = InitializeSid([0, 0, 0, 0, 0, 5], &sub_auths).unwrap();
+///
+/// assert_eq!(sid.id_authority(), &[0, 0, 0, 0, 0, 5]);
+/// assert_eq!(sid.sub_authorities(), &sub_auths);
+///
+/// assert!(InitializeSid([0, 0, 0, 0, 0, 5], &[0; 16]).is_err());
+/// ```
+#[allow(non_snake_case)]
+pub fn InitializeSid(id_auth: [u8; 6], sub_auths: &[u32]) -> io::Result<LocalBox<Sid>> {
+    if sub_auths.len() > SID_MAX_SUB_AUTHORITIES as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "InitializeSid called with too many sub_auths",
+        ));
+    }
+
+    let count = sub_auths.len() as u8;
+    let sid: LocalBox<Sid> =
+        unsafe { LocalBox::try_allocate(true, wrappers::GetSidLengthRequired(count))? };
+
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::InitializeSid(
+            sid.as_ptr() as *mut _,
+            &mut crate::ffi::um::winnt::SID_IDENTIFIER_AUTHORITY { Value: id_auth },
+            count,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    for (index, sub_auth) in sub_auths.iter().enumerate() {
+        // The SID was allocated with room for exactly `count` sub-authorities
+        unsafe {
+            *crate::ffi::um::securitybaseapi::GetSidSubAuthority(
+                sid.as_ptr() as *mut _,
+                index as u32,
+            ) = *sub_auth;
+        }
+    }
+
+    Ok(sid)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_allocated_sids() {
+        for (sid, id_auth, sub_auths) in Sid::test_sids() {
+            assert_eq!(InitializeSid(id_auth, sub_auths).unwrap(), sid);
+        }
+    }
+
+    #[test]
+    fn no_sub_auths() {
+        let sid = InitializeSid([0, 0, 0, 0, 0, 5], &[]).unwrap();
+
+        assert!(wrappers::IsValidSid(&sid));
+        assert_eq!(sid.sub_authority_count(), 0);
+    }
+}
@@ -4,7 +4,7 @@ use std::{
     ptr::null,
 };
 
-use winapi::um::{
+use crate::ffi::um::{
     winbase::LookupAccountNameW,
     winnt::{SID_NAME_USE, WCHAR},
 };
@@ -39,6 +39,10 @@ const MAX_RETRIES: usize = 5;
 /// assert_eq!(Box::as_ref(&sid), win_world_sid.as_ref());
 /// assert_eq!(name_use, SidNameUse::SidTypeWellKnownGroup);
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(system_name = ?system_name.as_ref().map(AsRef::as_ref), account_name = ?account_name.as_ref()), err(level = "debug"))
+)]
 #[allow(non_snake_case)]
 pub fn LookupAccountName(
     system_name: Option<impl AsRef<OsStr>>,
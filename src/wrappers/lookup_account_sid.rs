@@ -10,6 +10,11 @@ const BUFFER_SIZE: u32 = 256;
 ///
 /// Returns (name, domain).
 ///
+/// The Null SID (`S-1-0-0`) and the Anonymous Logon SID (`S-1-5-7`) are
+/// answered without calling into the OS, since the result of looking them up
+/// can vary between machines. They always map to `("NULL SID", "")` and
+/// `("ANONYMOUS LOGON", "NT AUTHORITY")` respectively.
+///
 /// ```
 /// use windows_permissions::{Sid, LocalBox, wrappers::LookupAccountSid};
 /// use winapi::um::winnt::WinBuiltinAdministratorsSid;
@@ -19,9 +24,23 @@ const BUFFER_SIZE: u32 = 256;
 ///
 /// assert_eq!(name, "Administrators");
 /// assert_eq!(domain, "BUILTIN");
+///
+/// let null_sid = "S-1-0-0".parse::<LocalBox<Sid>>().unwrap();
+/// let (name, domain) = LookupAccountSid(&null_sid).unwrap();
+///
+/// assert_eq!(name, "NULL SID");
+/// assert_eq!(domain, "");
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(sid = %sid), err(level = "debug"))
+)]
 #[allow(non_snake_case)]
 pub fn LookupAccountSid(sid: &Sid) -> Result<(OsString, OsString), io::Error> {
+    if let Some((name, domain)) = special_account(sid) {
+        return Ok((name.into(), domain.into()));
+    }
+
     let mut name_size = BUFFER_SIZE;
     let mut dom_size = BUFFER_SIZE;
 
@@ -35,7 +54,7 @@ pub fn LookupAccountSid(sid: &Sid) -> Result<(OsString, OsString), io::Error> {
         let mut name_use: u32 = 0;
 
         let result = unsafe {
-            winapi::um::winbase::LookupAccountSidW(
+            crate::ffi::um::winbase::LookupAccountSidW(
                 null(),
                 sid as *const Sid as *mut _,
                 name.as_mut_ptr(),
@@ -58,3 +77,51 @@ pub fn LookupAccountSid(sid: &Sid) -> Result<(OsString, OsString), io::Error> {
         }
     }
 }
+
+/// Get the fixed (name, domain) for SIDs that are handled without a lookup
+fn special_account(sid: &Sid) -> Option<(&'static str, &'static str)> {
+    match (sid.id_authority(), &sid.sub_authorities()[..]) {
+        ([0, 0, 0, 0, 0, 0], [0]) => Some(("NULL SID", "")),
+        ([0, 0, 0, 0, 0, 5], [7]) => Some(("ANONYMOUS LOGON", "NT AUTHORITY")),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::LocalBox;
+
+    #[test]
+    fn null_sid() {
+        let sid: LocalBox<Sid> = "S-1-0-0".parse().unwrap();
+
+        assert_eq!(
+            LookupAccountSid(&sid).unwrap(),
+            (OsString::from("NULL SID"), OsString::from(""))
+        );
+    }
+
+    #[test]
+    fn anonymous_sid() {
+        let sid: LocalBox<Sid> = "S-1-5-7".parse().unwrap();
+
+        assert_eq!(
+            LookupAccountSid(&sid).unwrap(),
+            (
+                OsString::from("ANONYMOUS LOGON"),
+                OsString::from("NT AUTHORITY")
+            )
+        );
+    }
+
+    #[test]
+    fn similar_sids_are_looked_up() {
+        // Only the exact SIDs are special-cased
+        let sid: LocalBox<Sid> = "S-1-5-18".parse().unwrap();
+        assert_eq!(special_account(&sid), None);
+
+        let sid: LocalBox<Sid> = "S-1-0-0-0".parse().unwrap();
+        assert_eq!(special_account(&sid), None);
+    }
+}
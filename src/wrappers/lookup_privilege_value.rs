@@ -0,0 +1,48 @@
+use crate::ffi::um::winnt::LUID;
+use crate::structures::Luid;
+use crate::utilities::buf_from_os;
+use std::ffi::OsStr;
+use std::io;
+use std::ptr::null;
+
+/// Wraps [`LookupPrivilegeValueW`](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-lookupprivilegevaluew)
+///
+/// Looks up the privilege on the local system.
+#[allow(non_snake_case)]
+pub fn LookupPrivilegeValue<S: AsRef<OsStr> + ?Sized>(name: &S) -> io::Result<Luid> {
+    let name = buf_from_os(name);
+    let mut luid = LUID {
+        LowPart: 0,
+        HighPart: 0,
+    };
+
+    let result =
+        unsafe { crate::ffi::um::winbase::LookupPrivilegeValueW(null(), name.as_ptr(), &mut luid) };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(Luid::from_raw(luid))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ffi::shared::winerror::ERROR_NO_SUCH_PRIVILEGE;
+    use crate::ffi::um::winnt::SE_SECURITY_NAME;
+
+    #[test]
+    fn known_privilege() {
+        assert!(LookupPrivilegeValue(SE_SECURITY_NAME).is_ok());
+    }
+
+    #[test]
+    fn unknown_privilege() {
+        let result = LookupPrivilegeValue("SeNotARealPrivilege");
+        assert_eq!(
+            result.err().and_then(|e| e.raw_os_error()),
+            Some(ERROR_NO_SUCH_PRIVILEGE as i32)
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::ffi::shared::winerror::ERROR_INSUFFICIENT_BUFFER;
+use crate::{LocalBox, SecurityDescriptor};
+use std::io;
+use std::ptr::null_mut;
+
+// Each component is placed on a pointer-aligned boundary within the allocation
+const ALIGN: u32 = std::mem::size_of::<usize>() as u32;
+
+fn align_up(size: u32) -> u32 {
+    (size + ALIGN - 1) & !(ALIGN - 1)
+}
+
+/// Wraps [`MakeAbsoluteSD`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-makeabsolutesd)
+///
+/// `sd` must be in self-relative format. The absolute descriptor and all of
+/// its components (owner, group, DACL, and SACL) are placed into a single
+/// local heap allocation, so the returned `LocalBox` owns everything that the
+/// absolute descriptor points to.
+///
+/// ```
+/// use windows_permissions::{LocalBox, SecurityDescriptor};
+/// use windows_permissions::constants::SdControl;
+/// use windows_permissions::wrappers::MakeAbsoluteSD;
+///
+/// let sd: LocalBox<SecurityDescriptor> = "O:SYG:BAD:(A;;FA;;;WD)".parse().unwrap();
+/// let absolute = MakeAbsoluteSD(&sd).unwrap();
+///
+/// assert!(!absolute.control().contains(SdControl::SelfRelative));
+/// assert_eq!(absolute.owner(), sd.owner());
+/// assert_eq!(absolute.as_sddl().unwrap(), sd.as_sddl().unwrap());
+/// ```
+#[allow(non_snake_case)]
+pub fn MakeAbsoluteSD(sd: &SecurityDescriptor) -> io::Result<LocalBox<SecurityDescriptor>> {
+    let mut sd_size = 0u32;
+    let mut dacl_size = 0u32;
+    let mut sacl_size = 0u32;
+    let mut owner_size = 0u32;
+    let mut group_size = 0u32;
+
+    // The first call only determines the sizes required
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::MakeAbsoluteSD(
+            sd as *const _ as *mut _,
+            null_mut(),
+            &mut sd_size,
+            null_mut(),
+            &mut dacl_size,
+            null_mut(),
+            &mut sacl_size,
+            null_mut(),
+            &mut owner_size,
+            null_mut(),
+            &mut group_size,
+        )
+    };
+
+    if result == 0 {
+        let error = io::Error::last_os_error();
+
+        if error.raw_os_error() != Some(ERROR_INSUFFICIENT_BUFFER as i32) {
+            return Err(error);
+        }
+    }
+
+    // Lay out the components one after the other
+    let dacl_offset = align_up(sd_size);
+    let sacl_offset = dacl_offset + align_up(dacl_size);
+    let owner_offset = sacl_offset + align_up(sacl_size);
+    let group_offset = owner_offset + align_up(owner_size);
+    let total_size = group_offset + align_up(group_size);
+
+    let absolute: LocalBox<SecurityDescriptor> =
+        unsafe { LocalBox::try_allocate(true, total_size as usize)? };
+
+    // Zero-sized components are not present, and get a null pointer
+    let component_ptr = |offset: u32, size: u32| {
+        if size == 0 {
+            null_mut()
+        } else {
+            unsafe { (absolute.as_ptr() as *mut u8).add(offset as usize) }
+        }
+    };
+
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::MakeAbsoluteSD(
+            sd as *const _ as *mut _,
+            absolute.as_ptr() as *mut _,
+            &mut sd_size,
+            component_ptr(dacl_offset, dacl_size) as *mut _,
+            &mut dacl_size,
+            component_ptr(sacl_offset, sacl_size) as *mut _,
+            &mut sacl_size,
+            component_ptr(owner_offset, owner_size) as *mut _,
+            &mut owner_size,
+            component_ptr(group_offset, group_size) as *mut _,
+            &mut group_size,
+        )
+    };
+
+    if result == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(absolute)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::ffi::shared::winerror::ERROR_INSUFFICIENT_BUFFER;
+use crate::{LocalBox, SecurityDescriptor};
+use std::io;
+use std::ptr::null_mut;
+
+/// Wraps [`MakeSelfRelativeSD`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-makeselfrelativesd)
+///
+/// `sd` must be in absolute format. The returned descriptor is a single
+/// self-contained allocation.
+///
+/// ```
+/// use windows_permissions::{LocalBox, SecurityDescriptor};
+/// use windows_permissions::constants::SdControl;
+/// use windows_permissions::wrappers::{MakeAbsoluteSD, MakeSelfRelativeSD};
+///
+/// let sd: LocalBox<SecurityDescriptor> = "O:SYD:(A;;FA;;;WD)".parse().unwrap();
+/// let absolute = MakeAbsoluteSD(&sd).unwrap();
+/// let relative = MakeSelfRelativeSD(&absolute).unwrap();
+///
+/// assert!(relative.control().contains(SdControl::SelfRelative));
+/// assert_eq!(relative.as_sddl().unwrap(), sd.as_sddl().unwrap());
+/// ```
+#[allow(non_snake_case)]
+pub fn MakeSelfRelativeSD(sd: &SecurityDescriptor) -> io::Result<LocalBox<SecurityDescriptor>> {
+    let mut len = 0u32;
+
+    // The first call only determines the size required
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::MakeSelfRelativeSD(
+            sd as *const _ as *mut _,
+            null_mut(),
+            &mut len,
+        )
+    };
+
+    if result == 0 {
+        let error = io::Error::last_os_error();
+
+        if error.raw_os_error() != Some(ERROR_INSUFFICIENT_BUFFER as i32) {
+            return Err(error);
+        }
+    }
+
+    let relative: LocalBox<SecurityDescriptor> =
+        unsafe { LocalBox::try_allocate(true, len as usize)? };
+
+    let result = unsafe {
+        crate::ffi::um::securitybaseapi::MakeSelfRelativeSD(
+            sd as *const _ as *mut _,
+            relative.as_ptr() as *mut _,
+            &mut len,
+        )
+    };
+
+    if result == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(relative)
+    }
+}
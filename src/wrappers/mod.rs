@@ -4,6 +4,13 @@
 //! can sometimes be useful to drop straight down into the raw WinAPI calls.
 //! These functions wrap the unsafe calls in safe objects, and are used to
 //! implement the other functionality in this crate.
+//!
+//! With the `tracing` feature enabled, wrappers that reach out to securable
+//! objects, account databases, or SDDL conversion emit a `debug`-level span
+//! per call. The span records the key arguments (object names, SID strings,
+//! information flags), and an event with the error (including the OS error
+//! code) is emitted if the call fails. Without the feature, no
+//! instrumentation is compiled in.
 
 // Implementation note
 //
@@ -12,23 +19,36 @@
 // test code in those sub-modules. However, tests that require multiple
 // wrapped calls should be placed here.
 
+mod access_check_by_type_result_list;
+mod add_access_allowed_denied_ace_ex;
 mod add_ace;
+mod add_audit_access_ace_ex;
+mod add_mandatory_ace;
+mod adjust_token_privileges;
 mod allocate_and_initialize_sid;
 mod build_trustee_with_name;
 mod build_trustee_with_sid;
+mod check_token_membership;
 mod convert_security_descriptor_to_string_security_descriptor;
 mod convert_sid_to_string_sid;
 mod convert_string_security_descriptor_to_security_descriptor;
 mod convert_string_sid_to_sid;
 mod copy_sid;
 mod create_well_known_sid;
+mod delete_ace;
+mod derive_capability_sids_from_name;
+mod duplicate_token;
 mod equal_sid;
 mod get_ace;
 mod get_acl_information;
 mod get_effective_rights_from_acl;
 mod get_named_security_info;
+mod get_named_security_info_with_timeout;
+mod get_security_descriptor_control;
 mod get_security_descriptor_dacl_sacl;
+mod get_security_descriptor_length;
 mod get_security_descriptor_owner_group;
+mod get_security_descriptor_rm_control;
 mod get_security_info;
 mod get_sid_identifier_authority;
 mod get_sid_length_required;
@@ -37,33 +57,62 @@ mod get_sid_sub_authority_count;
 mod get_trustee_form;
 mod get_trustee_name;
 mod get_windows_account_domain_sid;
+mod impersonate_self;
+mod initialize_acl;
+mod initialize_security_descriptor;
+mod initialize_sid;
 mod is_valid_acl;
 mod is_valid_security_descriptor;
 mod is_valid_sid;
 mod lookup_account_name;
 mod lookup_account_sid;
+mod lookup_privilege_value;
+mod make_absolute_sd;
+mod make_self_relative_sd;
+mod open_process_token;
+mod open_thread_token;
 mod set_named_security_info;
+mod set_security_descriptor_dacl_sacl;
+mod set_security_descriptor_owner_group;
+mod set_security_descriptor_rm_control;
 mod set_security_info;
+mod set_thread_token;
 
+pub use access_check_by_type_result_list::AccessCheckByTypeResultList;
+pub use add_access_allowed_denied_ace_ex::{AddAccessAllowedAceEx, AddAccessDeniedAceEx};
 pub use add_ace::AddAce;
+pub use add_audit_access_ace_ex::AddAuditAccessAceEx;
+pub use add_mandatory_ace::AddMandatoryAce;
+pub use adjust_token_privileges::AdjustTokenPrivileges;
 pub use allocate_and_initialize_sid::AllocateAndInitializeSid;
 pub use build_trustee_with_name::{BuildTrusteeWithName, BuildTrusteeWithNameOsStr};
 pub use build_trustee_with_sid::BuildTrusteeWithSid;
-pub use convert_security_descriptor_to_string_security_descriptor::ConvertSecurityDescriptorToStringSecurityDescriptor;
+pub use check_token_membership::CheckTokenMembership;
+pub use convert_security_descriptor_to_string_security_descriptor::{
+    ConvertSecurityDescriptorToStringSecurityDescriptor,
+    ConvertSecurityDescriptorToStringSecurityDescriptorWithRevision,
+};
 pub use convert_sid_to_string_sid::ConvertSidToStringSid;
 pub use convert_string_security_descriptor_to_security_descriptor::ConvertStringSecurityDescriptorToSecurityDescriptor;
 pub use convert_string_sid_to_sid::ConvertStringSidToSid;
 pub use copy_sid::CopySid;
 pub use create_well_known_sid::CreateWellKnownSid;
+pub use delete_ace::DeleteAce;
+pub use derive_capability_sids_from_name::DeriveCapabilitySidsFromName;
+pub use duplicate_token::DuplicateToken;
 pub use equal_sid::EqualSid;
 pub use get_ace::GetAce;
 pub use get_acl_information::GetAclInformationSize;
 pub use get_effective_rights_from_acl::GetEffectiveRightsFromAcl;
 pub use get_named_security_info::GetNamedSecurityInfo;
+pub use get_named_security_info_with_timeout::GetNamedSecurityInfoWithTimeout;
+pub use get_security_descriptor_control::GetSecurityDescriptorControl;
 pub use get_security_descriptor_dacl_sacl::{GetSecurityDescriptorDacl, GetSecurityDescriptorSacl};
+pub use get_security_descriptor_length::GetSecurityDescriptorLength;
 pub use get_security_descriptor_owner_group::{
     GetSecurityDescriptorGroup, GetSecurityDescriptorOwner,
 };
+pub use get_security_descriptor_rm_control::GetSecurityDescriptorRMControl;
 pub use get_security_info::GetSecurityInfo;
 pub use get_sid_identifier_authority::GetSidIdentifierAuthority;
 pub use get_sid_length_required::GetSidLengthRequired;
@@ -72,21 +121,36 @@ pub use get_sid_sub_authority_count::GetSidSubAuthorityCount;
 pub use get_trustee_form::GetTrusteeForm;
 pub use get_trustee_name::GetTrusteeName;
 pub use get_windows_account_domain_sid::GetWindowsAccountDomainSid;
+pub use impersonate_self::ImpersonateSelf;
+pub use initialize_acl::InitializeAcl;
+pub use initialize_security_descriptor::InitializeSecurityDescriptor;
+pub use initialize_sid::InitializeSid;
 pub use is_valid_acl::IsValidAcl;
 pub use is_valid_security_descriptor::IsValidSecurityDescriptor;
 pub use is_valid_sid::IsValidSid;
 pub use lookup_account_name::LookupAccountName;
 pub use lookup_account_sid::LookupAccountSid;
+pub use lookup_privilege_value::LookupPrivilegeValue;
+pub use make_absolute_sd::MakeAbsoluteSD;
+pub use make_self_relative_sd::MakeSelfRelativeSD;
+pub use open_process_token::OpenProcessToken;
+pub use open_thread_token::OpenThreadToken;
 pub use set_named_security_info::SetNamedSecurityInfo;
+pub use set_security_descriptor_dacl_sacl::{SetSecurityDescriptorDacl, SetSecurityDescriptorSacl};
+pub use set_security_descriptor_owner_group::{
+    SetSecurityDescriptorGroup, SetSecurityDescriptorOwner,
+};
+pub use set_security_descriptor_rm_control::SetSecurityDescriptorRMControl;
 pub use set_security_info::SetSecurityInfo;
+pub use set_thread_token::SetThreadToken;
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    use crate::ffi::um::winnt::{WinCapabilityMusicLibrarySid, WinLocalSid, WinWorldSid};
     use crate::Sid;
     use std::ffi::OsString;
-    use winapi::um::winnt::{WinCapabilityMusicLibrarySid, WinLocalSid, WinWorldSid};
 
     #[test]
     fn construct_and_read_sids() {
@@ -0,0 +1,37 @@
+use crate::Handle;
+use std::io;
+use std::ptr::null_mut;
+
+/// Wraps [`OpenProcessToken`](https://docs.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-openprocesstoken)
+///
+/// Always opens the token of the current process. `access` is a combination
+/// of the `TOKEN_*` access rights.
+#[allow(non_snake_case)]
+pub fn OpenProcessToken(access: u32) -> io::Result<Handle> {
+    let mut token = null_mut();
+
+    let result = unsafe {
+        crate::ffi::um::processthreadsapi::OpenProcessToken(
+            crate::ffi::um::processthreadsapi::GetCurrentProcess(),
+            access,
+            &mut token,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(unsafe { Handle::from_raw_owned(token as _) })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ffi::um::winnt::TOKEN_QUERY;
+
+    #[test]
+    fn open_current_token() {
+        assert!(OpenProcessToken(TOKEN_QUERY).is_ok());
+    }
+}
@@ -0,0 +1,56 @@
+use crate::Handle;
+use std::io;
+use std::ptr::null_mut;
+
+/// Wraps [`OpenThreadToken`](https://docs.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-openthreadtoken)
+///
+/// Always opens the token of the current thread. `access` is a combination
+/// of the `TOKEN_*` access rights. The access check uses the process's
+/// security context rather than the thread's.
+///
+/// Fails with `ERROR_NO_TOKEN` if the thread is not impersonating.
+#[allow(non_snake_case)]
+pub fn OpenThreadToken(access: u32) -> io::Result<Handle> {
+    let mut token = null_mut();
+
+    let result = unsafe {
+        crate::ffi::um::processthreadsapi::OpenThreadToken(
+            crate::ffi::um::processthreadsapi::GetCurrentThread(),
+            access,
+            1, // OpenAsSelf
+            &mut token,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(unsafe { Handle::from_raw_owned(token as _) })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constants::SecurityImpersonationLevel;
+    use crate::ffi::shared::winerror::ERROR_NO_TOKEN;
+    use crate::ffi::um::winnt::TOKEN_QUERY;
+    use crate::wrappers::{ImpersonateSelf, SetThreadToken};
+
+    #[test]
+    fn open_current_thread_token() -> io::Result<()> {
+        let err = OpenThreadToken(TOKEN_QUERY).unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(ERROR_NO_TOKEN as i32));
+
+        ImpersonateSelf(SecurityImpersonationLevel::SecurityImpersonation)?;
+        let token = OpenThreadToken(TOKEN_QUERY);
+        SetThreadToken(None)?;
+
+        assert!(token?.is_owned());
+
+        let err = OpenThreadToken(TOKEN_QUERY).unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(ERROR_NO_TOKEN as i32));
+
+        Ok(())
+    }
+}
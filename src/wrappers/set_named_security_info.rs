@@ -1,11 +1,15 @@
 use crate::constants::{SeObjectType, SecurityInformation};
+use crate::ffi::shared::winerror::ERROR_SUCCESS;
 use crate::utilities::{buf_from_os, ptr_from_opt};
 use crate::{Acl, Sid};
 use std::ffi::OsStr;
 use std::io;
-use winapi::shared::winerror::ERROR_SUCCESS;
 
 /// Wraps [`SetNamedSecurityInfoW`](https://docs.microsoft.com/en-us/windows/win32/api/aclapi/nf-aclapi-setnamedsecurityinfow)
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(name = ?name.as_ref(), ?obj_type, ?sec_info), err(level = "debug"))
+)]
 #[allow(non_snake_case)]
 pub fn SetNamedSecurityInfo<S: AsRef<OsStr> + ?Sized>(
     name: &S,
@@ -19,7 +23,7 @@ pub fn SetNamedSecurityInfo<S: AsRef<OsStr> + ?Sized>(
     let name = buf_from_os(name);
 
     let result_code = unsafe {
-        winapi::um::aclapi::SetNamedSecurityInfoW(
+        crate::ffi::um::aclapi::SetNamedSecurityInfoW(
             name.as_ptr() as *mut _,
             obj_type as u32,
             sec_info.bits(),
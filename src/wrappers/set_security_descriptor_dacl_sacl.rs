@@ -0,0 +1,49 @@
+use crate::utilities::ptr_from_opt;
+use crate::{Acl, SecurityDescriptor};
+use std::io;
+
+macro_rules! set_security_descriptor_acl {
+    ($f:ident; msdn: $msdn:expr) => {
+        set_security_descriptor_acl!(@ $f, concat!("Wraps [`", stringify!($f), "`](", $msdn, ")"));
+    };
+    (@ $f:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// If `present` is `false`, `acl` is ignored and the descriptor is
+        /// marked as having no ACL. If `present` is `true` but `acl` is `None`,
+        /// the descriptor gets a null ACL.
+        ///
+        /// # Safety
+        ///
+        /// - `sd` must be an absolute security descriptor. Self-relative
+        ///   descriptors are rejected by the underlying call.
+        /// - The descriptor only stores a pointer to `acl`, so `acl` must
+        ///   outlive every use of `sd`.
+        #[allow(non_snake_case)]
+        pub unsafe fn $f(
+            sd: &mut SecurityDescriptor,
+            present: bool,
+            acl: Option<&Acl>,
+            defaulted: bool,
+        ) -> io::Result<()> {
+            let result = crate::ffi::um::securitybaseapi::$f(
+                sd as *mut _ as *mut _,
+                present as i32,
+                ptr_from_opt(acl) as *mut _,
+                defaulted as i32,
+            );
+
+            if result == 0 {
+                Err(io::Error::last_os_error())
+            } else {
+                Ok(())
+            }
+        }
+    };
+}
+
+set_security_descriptor_acl!(SetSecurityDescriptorDacl;
+    msdn: "https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-setsecuritydescriptordacl");
+
+set_security_descriptor_acl!(SetSecurityDescriptorSacl;
+    msdn: "https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-setsecuritydescriptorsacl");
@@ -0,0 +1,45 @@
+use crate::utilities::ptr_from_opt;
+use crate::{SecurityDescriptor, Sid};
+use std::io;
+
+macro_rules! set_security_descriptor_sid {
+    ($f:ident; msdn: $msdn:expr) => {
+        set_security_descriptor_sid!(@ $f, concat!("Wraps [`", stringify!($f), "`](", $msdn, ")"));
+    };
+    (@ $f:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// Passing `None` removes the SID from the descriptor.
+        ///
+        /// # Safety
+        ///
+        /// - `sd` must be an absolute security descriptor. Self-relative
+        ///   descriptors are rejected by the underlying call.
+        /// - The descriptor only stores a pointer to `sid`, so `sid` must
+        ///   outlive every use of `sd`.
+        #[allow(non_snake_case)]
+        pub unsafe fn $f(
+            sd: &mut SecurityDescriptor,
+            sid: Option<&Sid>,
+            defaulted: bool,
+        ) -> io::Result<()> {
+            let result = crate::ffi::um::securitybaseapi::$f(
+                sd as *mut _ as *mut _,
+                ptr_from_opt(sid) as *mut _,
+                defaulted as i32,
+            );
+
+            if result == 0 {
+                Err(io::Error::last_os_error())
+            } else {
+                Ok(())
+            }
+        }
+    };
+}
+
+set_security_descriptor_sid!(SetSecurityDescriptorOwner;
+    msdn: "https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-setsecuritydescriptorowner");
+
+set_security_descriptor_sid!(SetSecurityDescriptorGroup;
+    msdn: "https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-setsecuritydescriptorgroup");
@@ -0,0 +1,49 @@
+use crate::ffi::shared::winerror::ERROR_SUCCESS;
+use crate::SecurityDescriptor;
+use std::io;
+use std::ptr::null_mut;
+
+/// Wraps [`SetSecurityDescriptorRMControl`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-setsecuritydescriptorrmcontrol)
+///
+/// Passing `None` clears the resource manager control bits. Only the
+/// descriptor header is changed, so this works on both absolute and
+/// self-relative descriptors.
+///
+/// ```
+/// use windows_permissions::{LocalBox, SecurityDescriptor};
+/// use windows_permissions::wrappers::{
+///     GetSecurityDescriptorRMControl, SetSecurityDescriptorRMControl,
+/// };
+///
+/// let mut sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;WD)".parse().unwrap();
+///
+/// SetSecurityDescriptorRMControl(&mut sd, Some(0x42)).unwrap();
+/// assert_eq!(GetSecurityDescriptorRMControl(&sd).unwrap(), Some(0x42));
+///
+/// SetSecurityDescriptorRMControl(&mut sd, None).unwrap();
+/// assert_eq!(GetSecurityDescriptorRMControl(&sd).unwrap(), None);
+/// ```
+#[allow(non_snake_case)]
+pub fn SetSecurityDescriptorRMControl(
+    sd: &mut SecurityDescriptor,
+    rm_control: Option<u8>,
+) -> io::Result<()> {
+    let mut rm_control = rm_control;
+    let rm_control_ptr = match rm_control.as_mut() {
+        Some(value) => value as *mut u8,
+        None => null_mut(),
+    };
+
+    let result_code = unsafe {
+        crate::ffi::um::securitybaseapi::SetSecurityDescriptorRMControl(
+            sd as *mut _ as *mut _,
+            rm_control_ptr,
+        )
+    };
+
+    if result_code == ERROR_SUCCESS {
+        Ok(())
+    } else {
+        Err(io::Error::from_raw_os_error(result_code as i32))
+    }
+}
@@ -1,14 +1,18 @@
 use crate::constants::{SeObjectType, SecurityInformation};
+use crate::ffi::shared::winerror::ERROR_SUCCESS;
 use crate::utilities::ptr_from_opt;
 use crate::{Acl, Sid};
 use std::io;
 use std::os::windows::io::AsRawHandle;
-use winapi::shared::winerror::ERROR_SUCCESS;
 
 /// Wraps [`SetSecurityInfo`](https://docs.microsoft.com/en-us/windows/win32/api/aclapi/nf-aclapi-setsecurityinfo)
 ///
 /// The flags set in `sec_info` determines what parameters are set; others are
 /// ignored following the semantics laid out in the WinAPI docs.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(handle = ?handle.as_raw_handle(), ?obj_type, ?sec_info), err(level = "debug"))
+)]
 #[allow(non_snake_case)]
 pub fn SetSecurityInfo<H: AsRawHandle>(
     handle: &mut H,
@@ -20,7 +24,7 @@ pub fn SetSecurityInfo<H: AsRawHandle>(
     sacl: Option<&Acl>,
 ) -> io::Result<()> {
     let result_code = unsafe {
-        winapi::um::aclapi::SetSecurityInfo(
+        crate::ffi::um::aclapi::SetSecurityInfo(
             handle.as_raw_handle(),
             obj_type as u32,
             sec_info.bits(),
@@ -0,0 +1,45 @@
+use crate::Handle;
+use std::io;
+use std::ptr::null_mut;
+
+/// Wraps [`SetThreadToken`](https://docs.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setthreadtoken)
+///
+/// Always sets the token of the current thread. The token must be an
+/// impersonation token with `TOKEN_IMPERSONATE` access. `None` makes the
+/// thread stop impersonating.
+#[allow(non_snake_case)]
+pub fn SetThreadToken(token: Option<&Handle>) -> io::Result<()> {
+    let result = unsafe {
+        crate::ffi::um::processthreadsapi::SetThreadToken(
+            null_mut(),
+            token.map_or(null_mut(), Handle::as_raw),
+        )
+    };
+
+    if result == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ffi::um::winnt::TOKEN_QUERY;
+    use crate::wrappers::OpenThreadToken;
+
+    #[test]
+    fn set_and_clear() -> io::Result<()> {
+        let token = Handle::impersonation_token();
+
+        SetThreadToken(Some(&token))?;
+        let opened = OpenThreadToken(TOKEN_QUERY);
+        SetThreadToken(None)?;
+
+        assert!(opened.is_ok());
+        assert!(OpenThreadToken(TOKEN_QUERY).is_err());
+
+        Ok(())
+    }
+}